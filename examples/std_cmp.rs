@@ -7,22 +7,23 @@ const STEP: f64 = 0.000001;
 /// On my computer, I get:
 /// ```ignore
 /// Func   | Total Tests| Diff Count|       Max Diff
-/// acos   |     2000000|     349419|    4.44089e-16
-/// acosh  |    99000001|    8505318|    8.88178e-16
-/// asin   |     2000000|     173790|    2.22045e-16
-/// asinh  |    99000001|    8714913|    8.88178e-16
-/// atan   |    50265483|    3290826|    2.22045e-16
-/// atanh  |     1999998|     771064|    3.34115e-11
-/// cos    |    50265483|    2173339|    1.11022e-16
-/// cosh   |    25132742|    6498663|    2.91038e-11
-/// ln     |    99999001|    3413955|    8.88178e-16
-/// exp    |    20000001|    1944323|    3.63798e-12
+/// acos   |     2000000|     151965|    4.44089e-16
+/// acosh  |    99000001|    8502450|    8.88178e-16
+/// asin   |     2000000|     118724|    2.22045e-16
+/// asinh  |    99000001|    6701247|    8.88178e-16
+/// atan   |    50265483|    1892404|    2.22045e-16
+/// atanh  |     1999998|     700972|    3.34115e-11
+/// cos    |    50265483|    1561029|    1.11022e-16
+/// cosh   |    25132742|    2350794|    2.91038e-11
+/// ln     |    99999001|    3436098|    8.88178e-16
+/// exp    |    20000001|    1944236|    3.63798e-12
 /// fabs   |    20000001|          0|      0.00000e0
 /// floor  |    20000001|          0|      0.00000e0
-/// sin    |    50265483|    2255609|    1.11022e-16
-/// sinh   |    25132742|    7200641|    2.91038e-11
-/// sqrt   |    10000001|    2500953|    4.44089e-16
-/// tan    |    50265483|   20777207|     3.72529e-9
+/// sin    |    50265483|    1558052|    1.11022e-16
+/// sinh   |    25132742|      22272|    2.91038e-11
+/// sqrt   |    10000001|          0|      0.00000e0
+/// tan    |    50265483|    1823396|    5.68434e-14
+/// tanh   |    25132742|     773985|    2.22045e-16
 /// ```
 fn main() {
     println!(
@@ -158,6 +159,14 @@ fn main() {
             std_fn: |x| x.tan(),
             const_fn: |x: f64| trig_const::tan(x),
         },
+        CompareArgs {
+            name: "tanh".to_string(),
+            start: -4.0 * PI,
+            stop: 4.0 * PI,
+            step: STEP,
+            std_fn: |x| x.tanh(),
+            const_fn: |x| trig_const::tanh(x),
+        },
     ];
 
     for test in tests {
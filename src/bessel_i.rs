@@ -0,0 +1,232 @@
+/* origin: Cephes Math Library Release 2.8: June, 2000, i0.c and i1.c */
+/*
+ * Copyright 1984, 1987, 2000 by Stephen L. Moshier
+ */
+//! Modified Bessel functions of the first kind, orders 0 and 1
+//!
+//! The range is partitioned into `[0, 8]` and `(8, inf)`. On `[0, 8]` the power
+//! series, whose terms are all positive, is summed directly; above 8 each
+//! function is a Chebyshev expansion:
+//!
+//! ```text
+//!     I0(x)                  = sum (x^2/4)^k / (k!)^2            for x <= 8
+//!     I1(x)                  = (x/2) sum (x^2/4)^k / (k! (k+1)!) for x <= 8
+//!     exp(-x) sqrt(x) I0(x)  = chbevl(32/x - 2, I0_B)            for x > 8
+//!     exp(-x) sqrt(x) I1(x)  = chbevl(32/x - 2, I1_B)            for x > 8
+//! ```
+//!
+//! The relative error is below 6e-16 for all four functions, scaled or not.
+
+use crate::{dd, exp, sqrt};
+
+/// Chebyshev coefficients for exp(-x) sqrt(x) I0(x) in the inverted interval
+/// [8, infinity], lim(x->inf) { exp(-x) sqrt(x) I0(x) } = 1/sqrt(2pi)
+const I0_B: [f64; 25] = [
+    -7.23318048787475395456e-18,
+    -4.83050448594418207126e-18,
+    4.46562142029675999901e-17,
+    3.4612228676974610931e-17,
+    -2.82762398051658348494e-16,
+    -3.42548561967721913462e-16,
+    1.7725601330565263836e-15,
+    3.81168066935262242075e-15,
+    -9.5548466988283076487e-15,
+    -4.15056934728722208663e-14,
+    1.54008621752140982691e-14,
+    3.85277838274214270114e-13,
+    7.18012445138366623367e-13,
+    -1.79417853150680611778e-12,
+    -1.32158118404477131188e-11,
+    -3.14991652796324136454e-11,
+    1.18891471078464383424e-11,
+    4.9406023882249695891e-10,
+    3.39623202570838634515e-9,
+    2.26666899049817806459e-8,
+    2.04891858946906374183e-7,
+    2.89137052083475648297e-6,
+    6.88975834691682398426e-5,
+    3.3691164782556940899e-3,
+    8.04490411014108831608e-1,
+];
+
+/// Chebyshev coefficients for exp(-x) sqrt(x) I1(x) in the inverted interval
+/// [8, infinity], lim(x->inf) { exp(-x) sqrt(x) I1(x) } = 1/sqrt(2pi)
+const I1_B: [f64; 25] = [
+    7.51729631084210480543e-18,
+    4.41434832307170794995e-18,
+    -4.65030536848935832557e-17,
+    -3.20952592199342395878e-17,
+    2.96262899764595013907e-16,
+    3.30820231092092828273e-16,
+    -1.88035477551078244851e-15,
+    -3.81440307243700780477e-15,
+    1.04202769841288027642e-14,
+    4.2724400167119513543e-14,
+    -2.10154184277266431302e-14,
+    -4.08355111109219731823e-13,
+    -7.19855177624590851209e-13,
+    2.03562854414708950722e-12,
+    1.41258074366137813316e-11,
+    3.25260358301548823856e-11,
+    -1.8974958123505412345e-11,
+    -5.58974346219658380687e-10,
+    -3.83538038596423702205e-9,
+    -2.63146884688951950684e-8,
+    -2.51223623787020892529e-7,
+    -3.88256480887769039346e-6,
+    -1.10588938762623716291e-4,
+    -9.76109749136146840777e-3,
+    7.78576235018280120474e-1,
+];
+
+/// Modified Bessel function of the first kind of order 0, `I0(x)`
+///
+/// Even in `x`. The relative error is below 6e-16, and `I0(x)` is finite up to
+/// `|x|` of about 713.98. This is the function in the Kaiser window,
+/// `I0(beta * sqrt(1 - (2n / N - 1)^2)) / I0(beta)`.
+///
+/// ```
+/// # use trig_const::bessel_i0;
+/// const I0_0: f64 = bessel_i0(0.0);
+/// assert_eq!(I0_0, 1.0);
+/// const I0_1: f64 = bessel_i0(1.0);
+/// assert!((I0_1 - 1.2660658777520084).abs() < 1e-15);
+/// ```
+pub const fn bessel_i0(x: f64) -> f64 {
+    let x = x.abs();
+    if x <= 8.0 {
+        return i0_series(x);
+    }
+    scale_exp(x, i0e_inner(x))
+}
+
+/// Modified Bessel function of the first kind of order 1, `I1(x)`
+///
+/// Odd in `x`. The relative error is below 6e-16, and `I1(x)` is finite up to
+/// `|x|` of about 713.98.
+///
+/// ```
+/// # use trig_const::bessel_i1;
+/// const I1_0: f64 = bessel_i1(0.0);
+/// assert_eq!(I1_0, 0.0);
+/// const I1_1: f64 = bessel_i1(1.0);
+/// assert!((I1_1 - 0.565159103992485).abs() < 1e-15);
+/// ```
+pub const fn bessel_i1(x: f64) -> f64 {
+    let ax = x.abs();
+    let r = if ax <= 8.0 {
+        i1_series(ax)
+    } else {
+        scale_exp(ax, i1e_inner(ax))
+    };
+    if x.is_sign_negative() {
+        -r
+    } else {
+        r
+    }
+}
+
+/// Exponentially scaled modified Bessel function of order 0, `exp(-|x|) I0(x)`
+///
+/// Does not overflow, and decays like `1 / sqrt(2 pi |x|)`.
+///
+/// ```
+/// # use trig_const::i0e;
+/// const I0E_0: f64 = i0e(0.0);
+/// assert_eq!(I0E_0, 1.0);
+/// const I0E_1000: f64 = i0e(1000.0);
+/// assert!((I0E_1000 - 0.012617240455891257).abs() < 1e-17);
+/// ```
+pub const fn i0e(x: f64) -> f64 {
+    i0e_inner(x.abs())
+}
+
+/// Exponentially scaled modified Bessel function of order 1, `exp(-|x|) I1(x)`
+///
+/// ```
+/// # use trig_const::i1e;
+/// const I1E_1: f64 = i1e(1.0);
+/// assert!((I1E_1 - 0.20791041534970845).abs() < 1e-16);
+/// ```
+pub const fn i1e(x: f64) -> f64 {
+    let r = i1e_inner(x.abs());
+    if x.is_sign_negative() {
+        -r
+    } else {
+        r
+    }
+}
+
+/// `I0(x) = sum q^k / (k!)^2` with `q = x^2/4`, for `0 <= x <= 8`
+///
+/// Every term is positive, so there is no cancellation.
+const fn i0_series(x: f64) -> f64 {
+    let (q, q_lo) = dd::two_prod(0.5 * x, 0.5 * x);
+    /* first-order correction for the rounding of q, using dI0/dq */
+    series(q, 0) + q_lo * series(q, 1)
+}
+
+/// `I1(x) = (x/2) sum q^k / (k! (k+1)!)` with `q = x^2/4`, for `0 <= x <= 8`
+const fn i1_series(x: f64) -> f64 {
+    let (q, q_lo) = dd::two_prod(0.5 * x, 0.5 * x);
+    0.5 * x * (series(q, 1) + q_lo * 0.5 * series(q, 2))
+}
+
+/// `n! * sum q^k / (k! (k+n)!)` for `q <= 16`, in Horner form so that the
+/// rounding errors of the small terms are damped
+const fn series(q: f64, n: u32) -> f64 {
+    /* the terms fall below 2^-60 of the sum by k = 28 */
+    let mut k = 28.0;
+    let mut sum = 1.0;
+    while k > 0.0 {
+        sum = 1.0 + sum * q / (k * (k + n as f64));
+        k -= 1.0;
+    }
+    sum
+}
+
+/// `exp(-x) I0(x)` for `x >= 0`
+const fn i0e_inner(x: f64) -> f64 {
+    if x <= 8.0 {
+        i0_series(x) * exp(-x)
+    } else {
+        chbevl(32.0 / x - 2.0, &I0_B) / sqrt(x)
+    }
+}
+
+/// `exp(-x) I1(x)` for `x >= 0`
+const fn i1e_inner(x: f64) -> f64 {
+    if x <= 8.0 {
+        i1_series(x) * exp(-x)
+    } else {
+        chbevl(32.0 / x - 2.0, &I1_B) / sqrt(x)
+    }
+}
+
+/// `exp(x) * r`, in two halves so that the result only overflows if it is
+/// out of range itself
+const fn scale_exp(x: f64, r: f64) -> f64 {
+    if x.is_nan() || x == f64::INFINITY {
+        return x;
+    }
+    if x < 709.0 {
+        return exp(x) * r;
+    }
+    let e = exp(0.5 * x);
+    e * r * e
+}
+
+/// Evaluate the Chebyshev series `coef[n-1]/2 + sum coef[n-1-k] T_k(x/2)`
+const fn chbevl(x: f64, coef: &[f64]) -> f64 {
+    let mut b0 = coef[0];
+    let mut b1 = 0.0;
+    let mut b2 = 0.0;
+    let mut i = 1;
+    while i < coef.len() {
+        b2 = b1;
+        b1 = b0;
+        b0 = x * b1 - b2 + coef[i];
+        i += 1;
+    }
+    0.5 * (b0 - b2)
+}
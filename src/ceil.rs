@@ -0,0 +1,54 @@
+/* SPDX-License-Identifier: MIT
+ * origin: musl src/math/ceil.c */
+
+//! Generic `ceil` algorithm, mirroring [`floor`](crate::floor).
+
+use crate::floor::{exp_unbiased, unsigned, SIG_BITS, SIG_MASK};
+
+/// Rounds up to the nearest integer
+///
+/// ```
+/// # use trig_const::ceil;
+/// const CEIL: f64 = ceil(-1.5);
+/// assert_eq!(CEIL, -1.0);
+/// ```
+pub const fn ceil(x: f64) -> f64 {
+    nightly_exp!(ceil, ceil_inner, x)
+}
+
+const fn ceil_inner(x: f64) -> f64 {
+    let zero = 0;
+
+    let mut ix = x.to_bits();
+    let e = exp_unbiased(x);
+
+    // If the represented value has no fractional part, no truncation is needed.
+    if e >= SIG_BITS as i32 {
+        return x;
+    }
+
+    if e >= 0 {
+        // |x| >= 1.0
+        let m = SIG_MASK >> unsigned(e);
+        if ix & m == zero {
+            // Portion to be masked is already zero; no adjustment needed.
+            return x;
+        }
+
+        if x.is_sign_positive() {
+            ix += m;
+        }
+
+        ix &= !m;
+        f64::from_bits(ix)
+    } else if x.is_sign_negative() {
+        // -1.0 < x <= -0.0; rounding up goes toward -0.0.
+        -0.0
+    } else if ix << 1 != zero {
+        // 0.0 < x < 1.0; rounding up goes toward +1.0.
+        1.0
+    } else {
+        // +0.0 remains unchanged
+        x
+    }
+}
@@ -0,0 +1,819 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/{s_cexp,k_exp,s_csqrt,s_ccosh,s_csinh,s_ctanh}.c */
+/*-
+ * Copyright (c) 2011 David Schultz <das@FreeBSD.ORG>
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+/*-
+ * Copyright (c) 2005 Bruce D. Evans and Steven G. Kargl
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice unmodified, this list of conditions, and the following
+ *    disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ * IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ * OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ * IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ * INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ * NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ * DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ * THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ * (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ * THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+//! Complex numbers
+//!
+//! [`Complex`] is a `#[repr(C)]` pair of `f64`, layout compatible with C's
+//! `double _Complex`. Arithmetic and the elementary functions are `const fn`,
+//! and follow the special-value rules of C99 Annex G: an infinite part is an
+//! infinity even when the other part is NaN, and the signs of zeros are kept.
+//!
+//! ```
+//! # use trig_const::complex::Complex;
+//! // Unit-magnitude rotation by 90°
+//! const ROT: Complex = Complex::cis(core::f64::consts::FRAC_PI_2);
+//! const Z: Complex = Complex::new(3.0, 4.0).mul(ROT);
+//! assert!((Z.re + 4.0).abs() < 1e-15);
+//! assert!((Z.im - 3.0).abs() < 1e-15);
+//! assert_eq!(Z.abs(), 5.0);
+//! ```
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{atan2, cosh, dd, exp, hypot, ln, log1p, scalbn, sincos, sinh, sqrt, tan};
+
+/// k * ln2 for k = 1799, used to scale `exp` past its overflow threshold
+const KLN2: f64 = 1246.97177782734161156; /* 0x40937BE3, 0x19BA0DA4 */
+const K: i32 = 1799;
+/// High word of `MAX_EXP * ln2`, about 709.78
+const EXP_OVFL: u32 = 0x40862e42;
+/// High word of `(MAX_EXP - MIN_DENORM_EXP) * ln2`, about 1454.9
+const CEXP_OVFL: u32 = 0x4096b8e4;
+/// High word of 22, above which `cosh(x)` and `sinh(x)` are `exp(|x|) / 2`
+const HIGH_22: u32 = 0x40360000;
+/// Past this, `csqrt` scales its argument down to avoid overflow
+const THRESH: f64 = 7.446288774449766e307; /* 0x7FDA8279, 0x99FCEF32 */
+
+/// A complex number `re + i im`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Complex {
+    /// Real part
+    pub re: f64,
+    /// Imaginary part
+    pub im: f64,
+}
+
+// The arithmetic methods are inherent so they can be `const`; the operator
+// traits below forward to them for runtime code.
+#[allow(clippy::should_implement_trait)]
+impl Complex {
+    /// `0 + 0i`
+    pub const ZERO: Complex = Complex::new(0.0, 0.0);
+    /// `1 + 0i`
+    pub const ONE: Complex = Complex::new(1.0, 0.0);
+    /// The imaginary unit, `0 + 1i`
+    pub const I: Complex = Complex::new(0.0, 1.0);
+
+    /// Complex number from its real and imaginary parts
+    pub const fn new(re: f64, im: f64) -> Self {
+        Complex { re, im }
+    }
+
+    /// `cos(θ) + i sin(θ)`, the point at angle `θ` on the unit circle
+    ///
+    /// ```
+    /// # use trig_const::complex::Complex;
+    /// const C: Complex = Complex::cis(0.0);
+    /// assert_eq!(C, Complex::ONE);
+    /// ```
+    pub const fn cis(theta: f64) -> Self {
+        let (s, c) = sincos(theta);
+        Complex::new(c, s)
+    }
+
+    /// Complex number from its magnitude `r` and argument `θ`
+    ///
+    /// ```
+    /// # use trig_const::complex::Complex;
+    /// const Z: Complex = Complex::from_polar(2.0, 0.0);
+    /// assert_eq!(Z, Complex::new(2.0, 0.0));
+    /// ```
+    pub const fn from_polar(r: f64, theta: f64) -> Self {
+        let (s, c) = sincos(theta);
+        Complex::new(r * c, r * s)
+    }
+
+    /// Complex conjugate, `re - i im`
+    pub const fn conj(self) -> Self {
+        Complex::new(self.re, -self.im)
+    }
+
+    /// Negation
+    pub const fn neg(self) -> Self {
+        Complex::new(-self.re, -self.im)
+    }
+
+    /// Sum `self + rhs`
+    pub const fn add(self, rhs: Self) -> Self {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+
+    /// Difference `self - rhs`
+    pub const fn sub(self, rhs: Self) -> Self {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+
+    /// Product with a real number
+    pub const fn scale(self, k: f64) -> Self {
+        Complex::new(self.re * k, self.im * k)
+    }
+
+    /// Product `self * rhs`
+    ///
+    /// Follows `_Cmultd` from C99 Annex G: if the naive product is NaN in both
+    /// parts but one of the factors is infinite, the result is an infinity.
+    ///
+    /// ```
+    /// # use trig_const::complex::Complex;
+    /// const Z: Complex = Complex::new(1.0, 2.0).mul(Complex::new(3.0, 4.0));
+    /// assert_eq!(Z, Complex::new(-5.0, 10.0));
+    /// const INF: Complex = Complex::new(f64::INFINITY, f64::NAN).mul(Complex::ONE);
+    /// assert!(INF.is_infinite());
+    /// ```
+    pub const fn mul(self, rhs: Self) -> Self {
+        let (mut a, mut b, mut c, mut d) = (self.re, self.im, rhs.re, rhs.im);
+        let (ac, bd, ad, bc) = (a * c, b * d, a * d, b * c);
+        let x = ac - bd;
+        let y = ad + bc;
+        if !(x.is_nan() && y.is_nan()) {
+            return Complex::new(x, y);
+        }
+
+        /* Recover infinities that computed as NaN + iNaN */
+        let mut recalc = false;
+        if a.is_infinite() || b.is_infinite() {
+            /* box the infinity and change NaNs in the other factor to 0 */
+            a = box_inf(a);
+            b = box_inf(b);
+            c = nan_to_zero(c);
+            d = nan_to_zero(d);
+            recalc = true;
+        }
+        if c.is_infinite() || d.is_infinite() {
+            c = box_inf(c);
+            d = box_inf(d);
+            a = nan_to_zero(a);
+            b = nan_to_zero(b);
+            recalc = true;
+        }
+        if !recalc && (ac.is_infinite() || bd.is_infinite() || ad.is_infinite() || bc.is_infinite())
+        {
+            /* recover infinities from overflow by changing NaNs to 0 */
+            a = nan_to_zero(a);
+            b = nan_to_zero(b);
+            c = nan_to_zero(c);
+            d = nan_to_zero(d);
+            recalc = true;
+        }
+        if recalc {
+            Complex::new(
+                f64::INFINITY * (a * c - b * d),
+                f64::INFINITY * (a * d + b * c),
+            )
+        } else {
+            Complex::new(x, y)
+        }
+    }
+
+    /// Quotient `self / rhs`
+    ///
+    /// Uses Smith's algorithm, which avoids the overflow of the textbook formula
+    /// for large divisors, and recovers infinities and zeros as `_Cdivd` from
+    /// C99 Annex G does.
+    ///
+    /// ```
+    /// # use trig_const::complex::Complex;
+    /// const Q: Complex = Complex::new(-5.0, 10.0).div(Complex::new(3.0, 4.0));
+    /// assert_eq!(Q, Complex::new(1.0, 2.0));
+    /// const BIG: Complex = Complex::new(1e300, 1e300).div(Complex::new(1e300, 1e300));
+    /// assert_eq!(BIG, Complex::ONE);
+    /// const POLE: Complex = Complex::ONE.div(Complex::ZERO);
+    /// assert!(POLE.is_infinite());
+    /// ```
+    pub const fn div(self, rhs: Self) -> Self {
+        let (a, b, c, d) = (self.re, self.im, rhs.re, rhs.im);
+        let (x, y) = if c.abs() >= d.abs() {
+            let r = d / c;
+            let den = c + d * r;
+            ((a + b * r) / den, (b - a * r) / den)
+        } else {
+            let r = c / d;
+            let den = c * r + d;
+            ((a * r + b) / den, (b * r - a) / den)
+        };
+        if !(x.is_nan() && y.is_nan()) {
+            return Complex::new(x, y);
+        }
+
+        /* Recover infinities and zeros that computed as NaN + iNaN */
+        if c == 0.0 && d == 0.0 && (!a.is_nan() || !b.is_nan()) {
+            let inf = f64::INFINITY.copysign(c);
+            Complex::new(inf * a, inf * b)
+        } else if (a.is_infinite() || b.is_infinite()) && c.is_finite() && d.is_finite() {
+            let (a, b) = (box_inf(a), box_inf(b));
+            Complex::new(
+                f64::INFINITY * (a * c + b * d),
+                f64::INFINITY * (b * c - a * d),
+            )
+        } else if (c.is_infinite() || d.is_infinite()) && a.is_finite() && b.is_finite() {
+            let (c, d) = (box_inf(c), box_inf(d));
+            Complex::new(0.0 * (a * c + b * d), 0.0 * (b * c - a * d))
+        } else {
+            Complex::new(x, y)
+        }
+    }
+
+    /// Reciprocal `1 / self`
+    pub const fn recip(self) -> Self {
+        Complex::ONE.div(self)
+    }
+
+    /// Whether either part is infinite
+    ///
+    /// Under Annex G such a value is an infinity even if the other part is NaN.
+    pub const fn is_infinite(self) -> bool {
+        self.re.is_infinite() || self.im.is_infinite()
+    }
+
+    /// Whether either part is NaN, and neither is infinite
+    pub const fn is_nan(self) -> bool {
+        !self.is_infinite() && (self.re.is_nan() || self.im.is_nan())
+    }
+
+    /// Whether both parts are finite
+    pub const fn is_finite(self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+
+    /// Squared magnitude, `re² + im²`
+    pub const fn norm_sqr(self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Magnitude `|z|`, computed with [`hypot`]
+    ///
+    /// ```
+    /// # use trig_const::complex::Complex;
+    /// const ABS: f64 = Complex::new(3e300, -4e300).abs();
+    /// assert_eq!(ABS, 5e300);
+    /// ```
+    pub const fn abs(self) -> f64 {
+        hypot(self.re, self.im)
+    }
+
+    /// Argument `arg(z)` in `[-π, π]`, computed with [`atan2`]
+    ///
+    /// The branch cut is the negative real axis; the sign of a zero imaginary
+    /// part picks the side.
+    ///
+    /// ```
+    /// # use trig_const::complex::Complex;
+    /// # use core::f64::consts::PI;
+    /// const UPPER: f64 = Complex::new(-1.0, 0.0).arg();
+    /// assert_eq!(UPPER, PI);
+    /// const LOWER: f64 = Complex::new(-1.0, -0.0).arg();
+    /// assert_eq!(LOWER, -PI);
+    /// ```
+    pub const fn arg(self) -> f64 {
+        atan2(self.im, self.re)
+    }
+
+    /// Complex exponential, `e^z`
+    ///
+    /// ```
+    /// # use trig_const::complex::Complex;
+    /// # use core::f64::consts::PI;
+    /// const EULER: Complex = Complex::new(0.0, PI).exp();
+    /// assert_eq!(EULER.re, -1.0);
+    /// assert!(EULER.im.abs() < 1e-15);
+    /// ```
+    pub const fn exp(self) -> Self {
+        let (x, y) = (self.re, self.im);
+        if y == 0.0 {
+            /* cexp(x + I 0) = exp(x) + I 0 */
+            return Complex::new(exp(x), y);
+        }
+        if x == 0.0 {
+            /* cexp(0 + I y) = cos(y) + I sin(y) */
+            return Complex::cis(y);
+        }
+        if !y.is_finite() {
+            return if x == f64::NEG_INFINITY {
+                /* cexp(-Inf +- I Inf|NaN) = 0 + I 0 */
+                Complex::ZERO
+            } else if x == f64::INFINITY {
+                /* cexp(+Inf +- I Inf|NaN) = Inf + I NaN */
+                Complex::new(x, f64::NAN)
+            } else {
+                /* cexp(finite|NaN +- I Inf|NaN) = NaN + I NaN */
+                Complex::new(f64::NAN, f64::NAN)
+            };
+        }
+        let hx = high_word(x);
+        if hx >= EXP_OVFL && hx <= CEXP_OVFL {
+            /* x is between 709.7 and 1454.3, so we must scale to avoid overflow in exp(x) */
+            ldexp_cexp(x, y, 0)
+        } else {
+            Complex::from_polar(exp(x), y)
+        }
+    }
+
+    /// Principal natural logarithm, `ln|z| + i arg(z)`
+    ///
+    /// The branch cut is the negative real axis. `ln(±0 + 0i)` is `-∞`.
+    ///
+    /// ```
+    /// # use trig_const::complex::Complex;
+    /// # use core::f64::consts::PI;
+    /// const LN_NEG_1: Complex = Complex::new(-1.0, 0.0).ln();
+    /// assert_eq!(LN_NEG_1, Complex::new(0.0, PI));
+    /// ```
+    pub const fn ln(self) -> Self {
+        let (x, y) = (self.re, self.im);
+        let (ax, ay) = (x.abs(), y.abs());
+        let (big, small) = if ax >= ay { (ax, ay) } else { (ay, ax) };
+        let re = if big > 0.71 && big < 1.41 {
+            /*
+             * near the unit circle: ln|z| = log1p(|z|^2 - 1) / 2,
+             * with |z|^2 - 1 in double-double
+             */
+            let (bh, bl) = dd::two_prod(big, big);
+            let (sh, sl) = dd::two_prod(small, small);
+            /* bh is in [0.5, 2], so bh - 1 is exact */
+            let (t, e) = dd::two_sum(bh - 1.0, sh);
+            0.5 * log1p(t + (e + bl + sl))
+        } else {
+            ln(hypot(x, y))
+        };
+        Complex::new(re, atan2(y, x))
+    }
+
+    /// Principal square root
+    ///
+    /// The result has a non-negative real part, and an imaginary part with the
+    /// sign of `im`. `sqrt(x ± i∞)` is `+∞ ± i∞` for every `x`, including NaN.
+    ///
+    /// ```
+    /// # use trig_const::complex::Complex;
+    /// const ROOT: Complex = Complex::new(-4.0, 0.0).sqrt();
+    /// assert_eq!(ROOT, Complex::new(0.0, 2.0));
+    /// const I: Complex = Complex::new(0.0, 2.0).sqrt();
+    /// assert_eq!(I, Complex::new(1.0, 1.0));
+    /// ```
+    pub const fn sqrt(self) -> Self {
+        let (mut a, mut b) = (self.re, self.im);
+
+        /* Handle special cases. */
+        if a == 0.0 && b == 0.0 {
+            return Complex::new(0.0, b);
+        }
+        if b.is_infinite() {
+            return Complex::new(f64::INFINITY, b);
+        }
+        if a.is_nan() {
+            /* csqrt(NaN + I y) = NaN + I NaN */
+            return Complex::new(a, f64::NAN);
+        }
+        if a.is_infinite() {
+            /*
+             * csqrt(inf + NaN i)  = inf +  NaN i
+             * csqrt(inf + y i)    = inf +  0 i
+             * csqrt(-inf + NaN i) = NaN +- inf i
+             * csqrt(-inf + y i)   = 0   +  inf i
+             */
+            return if a.is_sign_negative() {
+                let re = if b.is_nan() { b } else { 0.0 };
+                Complex::new(re, a.copysign(b))
+            } else {
+                let im = if b.is_nan() { b } else { 0.0f64.copysign(b) };
+                Complex::new(a, im)
+            };
+        }
+        /*
+         * The remaining special case (b is NaN) is handled just fine by
+         * the normal code path below.
+         */
+
+        /* Scale to avoid overflow, or the loss of precision of subnormals. */
+        let scale = if a.abs() >= THRESH || b.abs() >= THRESH {
+            a *= 0.25;
+            b *= 0.25;
+            2.0
+        } else if a.abs() < f64::MIN_POSITIVE * 2.0 && b.abs() < f64::MIN_POSITIVE * 2.0 {
+            a = scalbn(a, 54);
+            b = scalbn(b, 54);
+            7.450580596923828e-9 /* 0x1p-27 */
+        } else {
+            1.0
+        };
+
+        /* Algorithm 312, CACM vol 10, Oct 1967. */
+        let (re, im) = if a >= 0.0 {
+            let t = sqrt((a + hypot(a, b)) * 0.5);
+            (t, b / (2.0 * t))
+        } else {
+            let t = sqrt((-a + hypot(a, b)) * 0.5);
+            (b.abs() / (2.0 * t), t.copysign(b))
+        };
+        Complex::new(re * scale, im * scale)
+    }
+
+    /// Principal power `self^w = e^(w ln(self))`
+    ///
+    /// ```
+    /// # use trig_const::complex::Complex;
+    /// const I_I: Complex = Complex::I.pow(Complex::I);
+    /// assert!((I_I.re - 0.20787957635076193).abs() < 1e-16);
+    /// assert_eq!(I_I.im, 0.0);
+    /// ```
+    pub const fn pow(self, w: Self) -> Self {
+        w.mul(self.ln()).exp()
+    }
+
+    /// Complex hyperbolic cosine
+    ///
+    /// ```
+    /// # use trig_const::complex::Complex;
+    /// const C: Complex = Complex::new(1.0, 0.0).cosh();
+    /// const COSH_1: f64 = trig_const::cosh(1.0);
+    /// assert_eq!(C.re, COSH_1);
+    /// assert_eq!(C.im, 0.0);
+    /// ```
+    pub const fn cosh(self) -> Self {
+        let (x, y) = (self.re, self.im);
+
+        /* Handle the nearly-non-exceptional cases where x and y are finite. */
+        if x.is_finite() && y.is_finite() {
+            if y == 0.0 {
+                return Complex::new(cosh(x), x * y);
+            }
+            let ix = high_word(x) & 0x7fffffff;
+            if ix < HIGH_22 {
+                /* |x| < 22: normal case */
+                let (s, c) = sincos(y);
+                return Complex::new(cosh(x) * c, sinh(x) * s);
+            }
+            return scaled_cosh_sinh(x, y, false);
+        }
+
+        /*
+         * cosh(+-0 +- I Inf) = dNaN + I sign(d(+-0, dNaN))0.
+         * The sign of 0 in the result is unspecified.
+         */
+        if x == 0.0 {
+            return Complex::new(f64::NAN, 0.0);
+        }
+
+        /*
+         * cosh(+-Inf +- I 0) = +Inf + I (+-)(+-)0.
+         * cosh(NaN +- I 0)   = d(NaN) + I sign(d(NaN, +-0))0.
+         */
+        if y == 0.0 {
+            if x.is_infinite() {
+                return Complex::new(f64::INFINITY, 0.0f64.copysign(x) * y);
+            }
+            return Complex::new(x, 0.0);
+        }
+
+        /*
+         * cosh(x +- I Inf) = dNaN + I dNaN.
+         * cosh(x + I NaN)  = d(NaN) + I d(NaN).
+         */
+        if x.is_finite() {
+            return Complex::new(f64::NAN, f64::NAN);
+        }
+
+        /*
+         * cosh(+-Inf + I NaN)  = +Inf + I d(NaN).
+         * cosh(+-Inf +- I Inf) = dInf + I dNaN.
+         * cosh(+-Inf + I y)    = +Inf cos(y) +- I Inf sin(y)
+         */
+        if x.is_infinite() {
+            if !y.is_finite() {
+                return Complex::new(f64::INFINITY, f64::NAN);
+            }
+            let (s, c) = sincos(y);
+            return Complex::new(f64::INFINITY * c, x * s);
+        }
+
+        /*
+         * cosh(NaN + I NaN)  = d(NaN) + I d(NaN).
+         * cosh(NaN +- I Inf) = d(NaN) + I d(NaN).
+         * cosh(NaN + I y)    = d(NaN) + I d(NaN).
+         */
+        Complex::new(f64::NAN, f64::NAN)
+    }
+
+    /// Complex hyperbolic sine
+    ///
+    /// ```
+    /// # use trig_const::complex::Complex;
+    /// const S: Complex = Complex::new(0.0, 1.0).sinh();
+    /// const SIN_1: f64 = trig_const::sin(1.0);
+    /// assert_eq!(S, Complex::new(0.0, SIN_1));
+    /// ```
+    pub const fn sinh(self) -> Self {
+        let (x, y) = (self.re, self.im);
+
+        /* Handle the nearly-non-exceptional cases where x and y are finite. */
+        if x.is_finite() && y.is_finite() {
+            if y == 0.0 {
+                return Complex::new(sinh(x), y);
+            }
+            let ix = high_word(x) & 0x7fffffff;
+            if ix < HIGH_22 {
+                /* |x| < 22: normal case */
+                let (s, c) = sincos(y);
+                return Complex::new(sinh(x) * c, cosh(x) * s);
+            }
+            return scaled_cosh_sinh(x, y, true);
+        }
+
+        /*
+         * sinh(+-0 +- I Inf) = sign(d(+-0, dNaN))0 + I dNaN.
+         * The sign of 0 in the result is unspecified.
+         */
+        if x == 0.0 {
+            return Complex::new(x, f64::NAN);
+        }
+
+        /*
+         * sinh(+-Inf +- I 0) = +-Inf + I +-0.
+         * sinh(NaN +- I 0)   = d(NaN) + I +-0.
+         */
+        if y == 0.0 {
+            return Complex::new(x, y);
+        }
+
+        /*
+         * sinh(x +- I Inf) = dNaN + I dNaN.
+         * sinh(x + I NaN)  = d(NaN) + I d(NaN).
+         */
+        if x.is_finite() {
+            return Complex::new(f64::NAN, f64::NAN);
+        }
+
+        /*
+         * sinh(+-Inf + I NaN)  = +-Inf + I d(NaN).
+         * sinh(+-Inf +- I Inf) = +-Inf + I dNaN.
+         * sinh(+-Inf + I y)    = +-Inf cos(y) + I Inf sin(y)
+         */
+        if x.is_infinite() {
+            if !y.is_finite() {
+                return Complex::new(x, f64::NAN);
+            }
+            let (s, c) = sincos(y);
+            return Complex::new(x * c, f64::INFINITY * s);
+        }
+
+        /*
+         * sinh(NaN + I NaN)  = d(NaN) + I d(NaN).
+         * sinh(NaN +- I Inf) = d(NaN) + I d(NaN).
+         * sinh(NaN + I y)    = d(NaN) + I d(NaN).
+         */
+        Complex::new(f64::NAN, f64::NAN)
+    }
+
+    /// Complex hyperbolic tangent
+    ///
+    /// ```
+    /// # use trig_const::complex::Complex;
+    /// const T: Complex = Complex::new(800.0, 1.0).tanh();
+    /// assert_eq!(T.re, 1.0);
+    /// ```
+    pub const fn tanh(self) -> Self {
+        let (x, y) = (self.re, self.im);
+
+        /*
+         * ctanh(NaN +- I 0) = d(NaN) +- I 0
+         *
+         * ctanh(NaN + I y) = d(NaN,y) + I d(NaN,y)	for y != 0
+         *
+         * ctanh(+-Inf +- I Inf) = +-1 +- I 0
+         *
+         * ctanh(+-Inf + I y) = +-1 + I 0 sin(2y)	for y finite
+         */
+        if x.is_nan() {
+            return Complex::new(x, if y == 0.0 { y } else { f64::NAN });
+        }
+        if x.is_infinite() {
+            let im = if y.is_infinite() {
+                y
+            } else {
+                let (s, c) = sincos(y);
+                s * c
+            };
+            return Complex::new(1.0f64.copysign(x), 0.0f64.copysign(im));
+        }
+
+        /*
+         * ctanh(+0 + i NaN) = +0 + i NaN
+         * ctanh(+0 +- i Inf) = +0 + i NaN
+         * ctanh(x + i NaN) = NaN + i NaN
+         * ctanh(x +- i Inf) = NaN + i NaN
+         */
+        if !y.is_finite() {
+            return Complex::new(if x == 0.0 { x } else { f64::NAN }, f64::NAN);
+        }
+
+        /*
+         * ctanh(+-huge +- I y) ~= +-1 +- I 2sin(2y)/exp(2x), using the
+         * approximation sinh^2(huge) ~= exp(2*huge) / 4.
+         * We use a modified formula to avoid spurious overflow.
+         */
+        if high_word(x) & 0x7fffffff >= HIGH_22 {
+            let exp_mx = exp(-x.abs());
+            let (s, c) = sincos(y);
+            return Complex::new(1.0f64.copysign(x), 4.0 * s * c * exp_mx * exp_mx);
+        }
+
+        /*
+         * Kahan's algorithm, from "Branch Cuts for Complex Elementary
+         * Functions or Much Ado About Nothing's Sign Bit".
+         */
+        let t = tan(y);
+        let beta = 1.0 + t * t; /* = 1 / cos^2(y) */
+        let s = sinh(x);
+        let rho = sqrt(1.0 + s * s); /* = cosh(x) */
+        let denom = 1.0 + beta * s * s;
+        Complex::new((beta * rho * s) / denom, t / denom)
+    }
+
+    /// Complex cosine, `cos(z) = cosh(iz)`
+    ///
+    /// ```
+    /// # use trig_const::complex::Complex;
+    /// const C: Complex = Complex::new(0.0, 1.0).cos();
+    /// const COSH_1: f64 = trig_const::cosh(1.0);
+    /// assert_eq!(C.re, COSH_1);
+    /// ```
+    pub const fn cos(self) -> Self {
+        Complex::new(-self.im, self.re).cosh()
+    }
+
+    /// Complex sine, `sin(z) = -i sinh(iz)`
+    ///
+    /// ```
+    /// # use trig_const::complex::Complex;
+    /// const S: Complex = Complex::new(1.0, 0.0).sin();
+    /// const SIN_1: f64 = trig_const::sin(1.0);
+    /// assert_eq!(S, Complex::new(SIN_1, 0.0));
+    /// ```
+    pub const fn sin(self) -> Self {
+        let w = Complex::new(-self.im, self.re).sinh();
+        Complex::new(w.im, -w.re)
+    }
+
+    /// Complex tangent, `tan(z) = -i tanh(iz)`
+    ///
+    /// ```
+    /// # use trig_const::complex::Complex;
+    /// const T: Complex = Complex::new(0.0, 1.0).tan();
+    /// const TANH_1: f64 = trig_const::tanh(1.0);
+    /// assert_eq!(T, Complex::new(0.0, TANH_1));
+    /// ```
+    pub const fn tan(self) -> Self {
+        let w = Complex::new(-self.im, self.re).tanh();
+        Complex::new(w.im, -w.re)
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(re: f64) -> Self {
+        Complex::new(re, 0.0)
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, rhs: Self) -> Self {
+        Complex::add(self, rhs)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, rhs: Self) -> Self {
+        Complex::sub(self, rhs)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, rhs: Self) -> Self {
+        Complex::mul(self, rhs)
+    }
+}
+
+impl Div for Complex {
+    type Output = Complex;
+
+    fn div(self, rhs: Self) -> Self {
+        Complex::div(self, rhs)
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+
+    fn neg(self) -> Self {
+        Complex::neg(self)
+    }
+}
+
+/// Upper 32 bits of `x`
+const fn high_word(x: f64) -> u32 {
+    (x.to_bits() >> 32) as u32
+}
+
+/// `±1` for an infinite `x`, `±0` otherwise
+const fn box_inf(x: f64) -> f64 {
+    let b: f64 = if x.is_infinite() { 1.0 } else { 0.0 };
+    b.copysign(x)
+}
+
+/// `±0` for a NaN `x`, `x` otherwise
+const fn nan_to_zero(x: f64) -> f64 {
+    if x.is_nan() {
+        0.0f64.copysign(x)
+    } else {
+        x
+    }
+}
+
+/// `exp(x) * 2^expt * (cos(y) + I sin(y))`, for `x` in the range where `exp(x)`
+/// alone overflows but the scaled result may not
+const fn ldexp_cexp(x: f64, y: f64, expt: i32) -> Complex {
+    /* exp(x) = exp(x - k ln2) * 2^k, where the first factor is well in range */
+    let exp_x = exp(x - KLN2);
+    let (s, c) = sincos(y);
+    Complex::new(scalbn(c * exp_x, K + expt), scalbn(s * exp_x, K + expt))
+}
+
+/// `cosh(x + I y)` or `sinh(x + I y)` for finite `x` and `y` with `|x| >= 22`,
+/// where `cosh(x) ~= exp(|x|) / 2`
+const fn scaled_cosh_sinh(x: f64, y: f64, odd: bool) -> Complex {
+    let ix = high_word(x) & 0x7fffffff;
+    let sign = 1.0f64.copysign(x);
+    let (re, im) = if ix < EXP_OVFL {
+        /* x < 710: exp(|x|) won't overflow */
+        let h = exp(x.abs()) * 0.5;
+        let (s, c) = sincos(y);
+        (h * c, h * s)
+    } else if ix < 0x4096bbaa {
+        /* x < 1455: scale to avoid overflow */
+        let z = ldexp_cexp(x.abs(), y, -1);
+        (z.re, z.im)
+    } else {
+        /* x >= 1455: the result always overflows */
+        let (s, c) = sincos(y);
+        (f64::INFINITY * c, f64::INFINITY * s)
+    };
+    if odd {
+        Complex::new(re * sign, im)
+    } else {
+        Complex::new(re, im * sign)
+    }
+}
@@ -0,0 +1,44 @@
+/* origin: musl src/math/cosh.c */
+
+use crate::{exp, expm1::expm1, expo2::expo2};
+
+// cosh(x) = (exp(x) + 1/exp(x))/2
+//         = 1 + 0.5*(exp(x)-1)*(exp(x)-1)/exp(x)
+//         = 1 + x*x/2 + o(x^4)
+
+/// Hyperbolic Cosine
+///
+/// ```
+/// # use trig_const::cosh;
+/// const COSH_0: f64 = cosh(0.0);
+/// assert_eq!(COSH_0, 1.0);
+/// ```
+pub const fn cosh(x: f64) -> f64 {
+    nightly_exp!(cosh, cosh_inner, x)
+}
+
+const fn cosh_inner(x: f64) -> f64 {
+    /* |x| */
+    let x = x.abs();
+    let w = (x.to_bits() >> 32) as u32;
+
+    /* |x| < log(2) */
+    if w < 0x3fe62e42 {
+        if w < 0x3ff00000 - (26 << 20) {
+            /* raise inexact if x!=0 */
+            return 1.0;
+        }
+        let t = expm1(x);
+        return 1.0 + t * t / (2.0 * (1.0 + t));
+    }
+
+    /* |x| < log(DBL_MAX) */
+    if w < 0x40862e42 {
+        let t = exp(x);
+        /* note: if x>log(0x1p26) then the 1/t is not needed */
+        return 0.5 * (t + 1.0 / t);
+    }
+
+    /* |x| > log(DBL_MAX) or nan */
+    expo2(x)
+}
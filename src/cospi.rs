@@ -0,0 +1,133 @@
+// origin: FreeBSD /usr/src/lib/msun/src/s_cospi.c
+//
+// ====================================================
+// Copyright (c) 2017 Steven G. Kargl
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions
+// are met:
+// 1. Redistributions of source code must retain the above copyright
+//    notice unmodified, this list of conditions, and the following
+//    disclaimer.
+// 2. Redistributions in binary form must reproduce the above copyright
+//    notice, this list of conditions and the following disclaimer in the
+//    documentation and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+// IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+// OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+// IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+// INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+// NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+// DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+// THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+// (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+// THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+// ====================================================
+//
+// The principal argument of cospi(x) is x - n, with 0 <= x - n < 1 and n
+// the integer part of |x|.  Then
+//
+//   cospi(x) = cospi(x - n) * (-1)^n
+//
+// and cospi(x - n) is evaluated by one of the kernels on [0, 0.25]:
+//
+//   [0, 0.25)    cospi(r)       = k_cospi(r)
+//   [0.25, 0.5)  cospi(r)       = k_sinpi(0.5 - r)
+//   [0.5, 0.75)  cospi(r)       = -k_sinpi(r - 0.5)
+//   [0.75, 1)    cospi(r)       = -k_cospi(1 - r)
+//
+// Special cases:
+//   cospi(+-0) = 1.
+//   cospi(n.5) = +0, for n an integer.
+//   cospi(+-inf) = nan.  Raises the "invalid" floating-point exception.
+//   cospi(nan) = nan.  Raises the "invalid" floating-point exception.
+
+use crate::{floor, k_cospi::k_cospi, k_sinpi::k_sinpi};
+
+/// Cosine of π times x, cos(πx)
+///
+/// ```
+/// # use trig_const::cospi;
+/// const COSPI_1_2: f64 = cospi(0.5);
+/// assert_eq!(COSPI_1_2, 0.0);
+/// const COSPI_1: f64 = cospi(1.0);
+/// assert_eq!(COSPI_1, -1.0);
+/// ```
+pub const fn cospi(x: f64) -> f64 {
+    let ix = (x.to_bits() >> 32) as u32 & 0x7fffffff;
+    let mut ax = x.abs();
+
+    if ix < 0x3ff00000 {
+        /* |x| < 1 */
+        if ix < 0x3fd00000 {
+            /* |x| < 0.25 */
+            if ix < 0x3e200000 {
+                /* |x| < 0x1p-29 */
+                if ax as i32 == 0 {
+                    return 1.0;
+                }
+            }
+            return k_cospi(ax);
+        }
+
+        return if ix < 0x3fe00000 {
+            /* |x| < 0.5 */
+            k_sinpi(0.5 - ax)
+        } else if ix < 0x3fe80000 {
+            /* |x| < 0.75 */
+            if ax == 0.5 {
+                return 0.0;
+            }
+            -k_sinpi(ax - 0.5)
+        } else {
+            -k_cospi(1.0 - ax)
+        };
+    }
+
+    if ix < 0x43300000 {
+        /* 1 <= |x| < 0x1p52 */
+        let n = floor(ax); /* Integer part of ax. */
+        ax -= n;
+        let ix = (ax.to_bits() >> 32) as u32;
+
+        let c = if ix < 0x3fe00000 {
+            /* |x| < 0.5 */
+            if ix < 0x3fd00000 {
+                /* |x| < 0.25 */
+                if ax == 0.0 {
+                    1.0
+                } else {
+                    k_cospi(ax)
+                }
+            } else {
+                k_sinpi(0.5 - ax)
+            }
+        } else if ix < 0x3fe80000 {
+            /* |x| < 0.75 */
+            if ax == 0.5 {
+                return 0.0;
+            }
+            -k_sinpi(ax - 0.5)
+        } else {
+            -k_cospi(1.0 - ax)
+        };
+
+        return if n as u64 & 1 == 1 { -c } else { c };
+    }
+
+    if ix >= 0x7ff00000 {
+        /* x = +-inf or nan. */
+        return f64::NAN;
+    }
+
+    // For 0x1p52 <= |x| < 0x1p53 need to determine if x is an even
+    // or odd integer to return +1 or -1.
+    // For |x| >= 0x1p53, it is always an even integer, so return 1.
+    if ix < 0x43400000 && x.to_bits() & 1 == 1 {
+        -1.0
+    } else {
+        1.0
+    }
+}
@@ -0,0 +1,49 @@
+//! Double-double arithmetic
+//!
+//! A value is carried as an unevaluated sum `hi + lo` with `|lo| <= ulp(hi) / 2`,
+//! giving roughly 106 bits of precision. Products use Dekker's splitting, so no
+//! fused multiply-add is required.
+
+/// Split `a` into two halves of 26 bits each, such that `a = hi + lo` exactly
+const fn split(a: f64) -> (f64, f64) {
+    const SPLITTER: f64 = 134217729.0; /* 2^27 + 1 */
+    let t = SPLITTER * a;
+    let hi = t - (t - a);
+    (hi, a - hi)
+}
+
+/// Exact product `a * b = p + err` (Dekker)
+pub(crate) const fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    let (ah, al) = split(a);
+    let (bh, bl) = split(b);
+    let err = ((ah * bh - p) + ah * bl + al * bh) + al * bl;
+    (p, err)
+}
+
+/// Exact sum `a + b = s + err` (Knuth)
+pub(crate) const fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// Exact sum `a + b = s + err`, provided `|a| >= |b|`
+pub(crate) const fn fast_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+/// Double-double product
+pub(crate) const fn mul(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    let (p, err) = two_prod(a.0, b.0);
+    fast_two_sum(p, err + (a.0 * b.1 + a.1 * b.0))
+}
+
+/// Double-double reciprocal
+pub(crate) const fn recip(a: (f64, f64)) -> (f64, f64) {
+    let q = 1.0 / a.0;
+    let (p, err) = two_prod(q, a.0);
+    let r = ((1.0 - p) - err) - q * a.1;
+    fast_two_sum(q, r / a.0)
+}
@@ -0,0 +1,311 @@
+//! Trigonometric functions in degrees
+//!
+//! Arguments are reduced modulo 360 exactly, in degrees, before a single
+//! conversion of the remainder (at most 45°) to radians in double-double
+//! precision. Multiples of 30° and 45° never go through that conversion, so
+//! the canonical values 0, ±0.5 and ±1 come out exactly.
+
+use core::f64::consts::FRAC_1_SQRT_2;
+
+use crate::{acos, asin, atan, atan2, dd, floor, k_cos::k_cos, k_sin::k_sin, k_tan::k_tan};
+
+/// pi/180 as a double-double
+const DEG_TO_RAD_HI: f64 = 1.74532925199432954744e-02; /* 0x3F91DF46, 0xA2529D39 */
+const DEG_TO_RAD_LO: f64 = 2.94865227087016875300e-19; /* 0x3C15C1D8, 0xBECDD291 */
+/// 180/pi as a double-double
+const RAD_TO_DEG_HI: f64 = 5.72957795130823228646e+01; /* 0x404CA5DC, 0x1A63C1F8 */
+const RAD_TO_DEG_LO: f64 = -1.98784956705762830198e-15; /* 0xBCE1E7AB, 0x456405F9 */
+/// sqrt(3)/2, correctly rounded
+const SQRT_3_2: f64 = 8.66025403784438596588e-01; /* 0x3FEBB67A, 0xE8584CAA */
+/// 1/sqrt(3), correctly rounded
+const FRAC_1_SQRT_3: f64 = 5.77350269189625731059e-01; /* 0x3FE279A7, 0x4590331C */
+/// sqrt(3), correctly rounded
+const SQRT_3: f64 = 1.73205080756887719318e+00; /* 0x3FFBB67A, 0xE8584CAA */
+
+/// Sine of an angle in degrees
+///
+/// ```
+/// # use trig_const::sind;
+/// const SIN_30: f64 = sind(30.0);
+/// assert_eq!(SIN_30, 0.5);
+/// const SIN_180: f64 = sind(180.0);
+/// assert_eq!(SIN_180, 0.0);
+/// ```
+pub const fn sind(x: f64) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    let (q, t) = rem_90(x);
+    let (s, c) = sincosd_kernel(t);
+    let s = match q {
+        0 => s,
+        1 => c,
+        2 => -s,
+        _ => -c,
+    };
+    if s == 0.0 {
+        /* sind(180n) is +0 for positive and -0 for negative n */
+        return if x.is_sign_negative() { -0.0 } else { 0.0 };
+    }
+    s
+}
+
+/// Cosine of an angle in degrees
+///
+/// ```
+/// # use trig_const::cosd;
+/// const COS_60: f64 = cosd(60.0);
+/// assert_eq!(COS_60, 0.5);
+/// const COS_90: f64 = cosd(90.0);
+/// assert_eq!(COS_90, 0.0);
+/// ```
+pub const fn cosd(x: f64) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    let (q, t) = rem_90(x);
+    let (s, c) = sincosd_kernel(t);
+    let c = match q {
+        0 => c,
+        1 => -s,
+        2 => -c,
+        _ => s,
+    };
+    if c == 0.0 {
+        /* cosd(90 + 180n) is +0 */
+        return 0.0;
+    }
+    c
+}
+
+/// Tangent of an angle in degrees
+///
+/// `tand(90 + 180n)` is `+∞` for even `n` and `-∞` for odd `n`.
+///
+/// ```
+/// # use trig_const::tand;
+/// const TAN_45: f64 = tand(45.0);
+/// assert_eq!(TAN_45, 1.0);
+/// const TAN_90: f64 = tand(90.0);
+/// assert_eq!(TAN_90, f64::INFINITY);
+/// ```
+pub const fn tand(x: f64) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    let (q, t) = rem_90(x);
+    let odd = q & 1 == 1;
+    let at = t.abs();
+    let r = if t == 0.0 {
+        if odd {
+            /* pole at 90 + 180n */
+            return if q == 1 {
+                f64::INFINITY
+            } else {
+                f64::NEG_INFINITY
+            };
+        }
+        /* tand(180n) is +0 for positive even and negative odd n, -0 otherwise */
+        let negative = x.is_sign_negative() != (q == 2);
+        return if negative { -0.0 } else { 0.0 };
+    } else if at == 45.0 {
+        if odd {
+            -1.0
+        } else {
+            1.0
+        }
+    } else if at == 30.0 {
+        if odd {
+            -SQRT_3
+        } else {
+            FRAC_1_SQRT_3
+        }
+    } else {
+        let (hi, lo) = deg_to_rad(at);
+        k_tan(hi, lo, odd as i32)
+    };
+    if t < 0.0 {
+        -r
+    } else {
+        r
+    }
+}
+
+/// Arcsine in degrees
+///
+/// ```
+/// # use trig_const::asind;
+/// const ASIN_HALF: f64 = asind(0.5);
+/// assert_eq!(ASIN_HALF, 30.0);
+/// ```
+pub const fn asind(x: f64) -> f64 {
+    let ax = x.abs();
+    let d = if ax == 0.5 {
+        30.0
+    } else if ax == 1.0 {
+        90.0
+    } else {
+        return rad_to_deg(asin(x));
+    };
+    if x < 0.0 {
+        -d
+    } else {
+        d
+    }
+}
+
+/// Arccosine in degrees
+///
+/// ```
+/// # use trig_const::acosd;
+/// const ACOS_HALF: f64 = acosd(0.5);
+/// assert_eq!(ACOS_HALF, 60.0);
+/// ```
+pub const fn acosd(x: f64) -> f64 {
+    if x == 0.5 {
+        60.0
+    } else if x == -0.5 {
+        120.0
+    } else if x == 0.0 {
+        90.0
+    } else if x == 1.0 {
+        0.0
+    } else if x == -1.0 {
+        180.0
+    } else {
+        rad_to_deg(acos(x))
+    }
+}
+
+/// Arctangent in degrees
+///
+/// ```
+/// # use trig_const::atand;
+/// const ATAN_1: f64 = atand(1.0);
+/// assert_eq!(ATAN_1, 45.0);
+/// ```
+pub const fn atand(x: f64) -> f64 {
+    let ax = x.abs();
+    let d = if ax == 1.0 {
+        45.0
+    } else if ax == f64::INFINITY {
+        90.0
+    } else {
+        return rad_to_deg(atan(x));
+    };
+    if x < 0.0 {
+        -d
+    } else {
+        d
+    }
+}
+
+/// Two-argument arctangent in degrees
+///
+/// ```
+/// # use trig_const::atan2d;
+/// const ATAN2_1_NEG_1: f64 = atan2d(1.0, -1.0);
+/// assert_eq!(ATAN2_1_NEG_1, 135.0);
+/// ```
+pub const fn atan2d(y: f64, x: f64) -> f64 {
+    if y.is_nan() || x.is_nan() {
+        return y + x;
+    }
+    let (ay, ax) = (y.abs(), x.abs());
+    let d = if ay == ax {
+        /* the diagonals, including (+-0, +-0) and (+-inf, +-inf) */
+        if ay == 0.0 {
+            if x.is_sign_negative() {
+                180.0
+            } else {
+                0.0
+            }
+        } else if x < 0.0 {
+            135.0
+        } else {
+            45.0
+        }
+    } else if ax == 0.0 || ay == f64::INFINITY {
+        90.0
+    } else if ay == 0.0 || ax == f64::INFINITY {
+        if x < 0.0 {
+            180.0
+        } else {
+            0.0
+        }
+    } else {
+        return rad_to_deg(atan2(y, x));
+    };
+    if y.is_sign_negative() {
+        -d
+    } else {
+        d
+    }
+}
+
+/// Reduce finite `x` to `q * 90 + t`, with `q` in 0..4 and `|t| <= 45`, exactly
+const fn rem_90(x: f64) -> (u32, f64) {
+    let r = rem_360(x);
+    /* r is in [-180, 180], so |r - 90q| <= |r| and the subtraction is exact */
+    let q = floor(r / 90.0 + 0.5);
+    let t = r - 90.0 * q;
+    ((q as i32 & 3) as u32, t)
+}
+
+/// `x` modulo 360, in [-180, 180], computed exactly
+const fn rem_360(x: f64) -> f64 {
+    let ax = x.abs();
+    if ax < 4503599627370496.0 {
+        /* |x| < 2^52: 360k is exact, and so is x - 360k since |x - 360k| <= |x| */
+        let k = floor(x / 360.0 + 0.5);
+        return x - 360.0 * k;
+    }
+
+    /* |x| = m * 2^e is an integer; reduce m and 2^e separately */
+    let bits = ax.to_bits();
+    let m = (bits & ((1 << 52) - 1)) | (1 << 52);
+    let mut e = (bits >> 52) as i32 - 1075;
+    let mut r = m % 360;
+    while e > 0 {
+        r = (2 * r) % 360;
+        e -= 1;
+    }
+    let r = if r > 180 { r as f64 - 360.0 } else { r as f64 };
+    if x < 0.0 {
+        -r
+    } else {
+        r
+    }
+}
+
+/// Sine and cosine of `t` degrees, for `|t| <= 45`
+const fn sincosd_kernel(t: f64) -> (f64, f64) {
+    let at = t.abs();
+    let (s, c) = if at == 0.0 {
+        (0.0, 1.0)
+    } else if at == 30.0 {
+        (0.5, SQRT_3_2)
+    } else if at == 45.0 {
+        (FRAC_1_SQRT_2, FRAC_1_SQRT_2)
+    } else {
+        let (hi, lo) = deg_to_rad(at);
+        (k_sin(hi, lo, 1), k_cos(hi, lo))
+    };
+    if t.is_sign_negative() {
+        (-s, c)
+    } else {
+        (s, c)
+    }
+}
+
+/// `x` degrees in radians, as a double-double
+const fn deg_to_rad(x: f64) -> (f64, f64) {
+    let (p, err) = dd::two_prod(x, DEG_TO_RAD_HI);
+    dd::fast_two_sum(p, err + x * DEG_TO_RAD_LO)
+}
+
+/// `x` radians in degrees
+const fn rad_to_deg(x: f64) -> f64 {
+    let (p, err) = dd::two_prod(x, RAD_TO_DEG_HI);
+    p + (err + x * RAD_TO_DEG_LO)
+}
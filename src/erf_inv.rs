@@ -0,0 +1,224 @@
+//! Inverse error functions
+//!
+//! The initial estimate is Giles' double precision approximation ("Approximating
+//! the erfinv function", GPU Computing Gems, 2011), which is accurate to a few
+//! ulp on its own. One Newton step against [`erf`](crate::erf) or
+//! [`erfc`](crate::erfc) then leaves an error below 2 ulp, dominated by the
+//! error of `erf` itself. Below `2^-54`, where Giles' approximation no longer
+//! applies, `erfc_inv` starts from the asymptotic expansion of `erfc` and takes
+//! three Newton steps on `ln(erfc(x))`.
+
+use core::f64::consts::FRAC_2_SQRT_PI;
+
+use crate::{
+    erf::{erf_inner, erfc_inner, erfcx_tail},
+    exp, ln, sqrt,
+};
+
+/// Giles' polynomial for `w = -ln((1 - x) * (1 + x)) < 6.25`, in `w - 3.125`
+const P_CENTRAL: [f64; 23] = [
+    -3.6444120640178196996e-21,
+    -1.685059138182016589e-19,
+    1.2858480715256400167e-18,
+    1.115787767802518096e-17,
+    -1.333171662854620906e-16,
+    2.0972767875968561637e-17,
+    6.6376381343583238325e-15,
+    -4.0545662729752068639e-14,
+    -8.1519341976054721522e-14,
+    2.6335093153082322977e-12,
+    -1.2975133253453532498e-11,
+    -5.4154120542946279317e-11,
+    1.051212273321532285e-09,
+    -4.1126339803469836976e-09,
+    -2.9070369957882005086e-08,
+    4.2347877827932403518e-07,
+    -1.3654692000834678645e-06,
+    -1.3882523362786468719e-05,
+    0.0001867342080340571352,
+    -0.00074070253416626697512,
+    -0.0060336708714301490533,
+    0.24015818242558961693,
+    1.6536545626831027356,
+];
+/// Giles' polynomial for `6.25 <= w < 16`, in `sqrt(w) - 3.25`
+const P_INNER_TAIL: [f64; 19] = [
+    2.2137376921775787049e-09,
+    9.0756561938885390979e-08,
+    -2.7517406297064545428e-07,
+    1.8239629214389227755e-08,
+    1.5027403968909827627e-06,
+    -4.013867526981545969e-06,
+    2.9234449089955446044e-06,
+    1.2475304481671778723e-05,
+    -4.7318229009055733981e-05,
+    6.8284851459573175448e-05,
+    2.4031110387097893999e-05,
+    -0.0003550375203628474796,
+    0.00095328937973738049703,
+    -0.0016882755560235047313,
+    0.0024914420961078508066,
+    -0.0037512085075692412107,
+    0.005370914553590063617,
+    1.0052589676941592334,
+    3.0838856104922207635,
+];
+/// Giles' polynomial for `16 <= w`, in `sqrt(w) - 5`
+const P_OUTER_TAIL: [f64; 17] = [
+    -2.7109920616438573243e-11,
+    -2.5556418169965252055e-10,
+    1.5076572693500548083e-09,
+    -3.7894654401267369937e-09,
+    7.6157012080783393804e-09,
+    -1.4960026627149240478e-08,
+    2.9147953450901080826e-08,
+    -6.7711997758452339498e-08,
+    2.2900482228026654717e-07,
+    -9.9298272942317002539e-07,
+    4.5260625972231537039e-06,
+    -1.9681778105531670567e-05,
+    7.5995277030017761139e-05,
+    -0.00021503011930044477347,
+    -0.00013871931833623122026,
+    1.0103004648645343977,
+    4.8499064014085844221,
+];
+/// sqrt(pi)/2
+const SQRT_PI_2: f64 = 8.86226925452758013649e-01; /* 0x3FEC5BF8, 0x91B4EF6B */
+/// ln(sqrt(pi))
+const LN_SQRT_PI: f64 = 5.72364942924700087072e-01; /* 0x3FE250D0, 0x48E7A1BD */
+
+/// Inverse error function
+///
+/// Returns `x` such that `erf(x) == y`, for `y` in `[-1, 1]`. `erf_inv(±1)`
+/// is `±∞` and arguments outside `[-1, 1]` give NaN. The error is below
+/// 2 ulp.
+///
+/// ```
+/// # use trig_const::erf_inv;
+/// const ERF_INV: f64 = erf_inv(0.5);
+/// assert_eq!(ERF_INV, 0.4769362762044699);
+/// const ERF_INV_1: f64 = erf_inv(-1.0);
+/// assert_eq!(ERF_INV_1, f64::NEG_INFINITY);
+/// ```
+pub const fn erf_inv(y: f64) -> f64 {
+    let ay = y.abs();
+    if y.is_nan() || ay > 1.0 {
+        /* erf_inv(nan) and erf_inv(|y| > 1) are nan */
+        return f64::NAN;
+    }
+    if ay == 0.0 {
+        return y;
+    }
+    let x = if ay <= 0.5 {
+        inv_central(ay)
+    } else if ay == 1.0 {
+        f64::INFINITY
+    } else {
+        /* 1 - |y| is exact */
+        inv_tail(1.0 - ay)
+    };
+    if y < 0.0 {
+        -x
+    } else {
+        x
+    }
+}
+
+/// Inverse complementary error function
+///
+/// Returns `x` such that `erfc(x) == z`, for `z` in `[0, 2]`. `erfc_inv(0)`
+/// is `+∞`, `erfc_inv(2)` is `-∞` and arguments outside `[0, 2]` give NaN.
+/// Small `z` is handled directly, down to the smallest subnormal. The error
+/// is below 2 ulp.
+///
+/// ```
+/// # use trig_const::erfc_inv;
+/// const ERFC_INV: f64 = erfc_inv(1.0);
+/// assert_eq!(ERFC_INV, 0.0);
+/// const ERFC_INV_TINY: f64 = erfc_inv(1e-300);
+/// assert!((ERFC_INV_TINY - 26.209469960516124).abs() < 1e-14);
+/// ```
+pub const fn erfc_inv(z: f64) -> f64 {
+    if z.is_nan() || z < 0.0 || z > 2.0 {
+        /* erfc_inv(nan) and erfc_inv(z) outside [0, 2] are nan */
+        return f64::NAN;
+    }
+    /* each difference below is exact */
+    if z == 0.0 {
+        f64::INFINITY
+    } else if z <= 0.5 {
+        inv_tail(z)
+    } else if z < 1.0 {
+        inv_central(1.0 - z)
+    } else if z == 1.0 {
+        0.0
+    } else if z <= 1.5 {
+        -inv_central(z - 1.0)
+    } else if z < 2.0 {
+        -inv_tail(2.0 - z)
+    } else {
+        f64::NEG_INFINITY
+    }
+}
+
+/// `erf_inv(p)` for `p` in `(0, 0.5]`
+const fn inv_central(p: f64) -> f64 {
+    if p < 3.7252902984e-09 {
+        /* |p| < 2**-28, erf(x) = x * 2 / sqrt(pi) to within rounding */
+        return p * SQRT_PI_2;
+    }
+    let x = giles(p, 1.0 - p) * p;
+    /* Newton step on erf(x) - p */
+    x - (erf_inner(x) - p) / (FRAC_2_SQRT_PI * exp(-x * x))
+}
+
+/// `erfc_inv(q)` for `q` in `(0, 0.5]`
+const fn inv_tail(q: f64) -> f64 {
+    let p = 1.0 - q;
+    let (mut x, steps) = if q >= 5.5511151231257827e-17 {
+        /* q >= 2**-54 */
+        (giles(p, q) * p, 1)
+    } else {
+        /* erfc(x) ~ exp(-x * x) / (x * sqrt(pi)), so x * x ~ -ln(q) - ln(x * sqrt(pi)) */
+        let l = -ln(q);
+        let x0 = sqrt(l);
+        (sqrt(l - ln(x0) - LN_SQRT_PI), 3)
+    };
+    let ln_q = ln(q);
+    let mut i = 0;
+    while i < steps {
+        if x < 1.25 {
+            /* Newton step on erfc(x) - q */
+            x += (erfc_inner(x) - q) / (FRAC_2_SQRT_PI * exp(-x * x));
+        } else {
+            /* Newton step on ln(erfc(x)) - ln(q), scaled so nothing underflows */
+            let s = erfcx_tail(x);
+            x += (ln(s) - x * x - ln_q) * s / FRAC_2_SQRT_PI;
+        }
+        i += 1;
+    }
+    x
+}
+
+/// Giles' estimate of `erf_inv(p) / p`, given `p` and `q = 1 - p`
+const fn giles(p: f64, q: f64) -> f64 {
+    let w = -ln(q * (1.0 + p));
+    if w < 6.25 {
+        horner(&P_CENTRAL, w - 3.125)
+    } else if w < 16.0 {
+        horner(&P_INNER_TAIL, sqrt(w) - 3.25)
+    } else {
+        horner(&P_OUTER_TAIL, sqrt(w) - 5.0)
+    }
+}
+
+const fn horner(coefficients: &[f64], x: f64) -> f64 {
+    let mut p = coefficients[0];
+    let mut i = 1;
+    while i < coefficients.len() {
+        p = coefficients[i] + p * x;
+        i += 1;
+    }
+    p
+}
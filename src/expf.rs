@@ -0,0 +1,118 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/e_expf.c */
+/*
+ * Conversion to float by Ian Lance Taylor, Cygnus Support, ian@cygnus.com.
+ */
+/*
+ * ====================================================
+ * Copyright (C) 1993 by Sun Microsystems, Inc. All rights reserved.
+ *
+ * Developed at SunPro, a Sun Microsystems, Inc. business.
+ * Permission to use, copy, modify, and distribute this
+ * software is freely granted, provided that this notice
+ * is preserved.
+ * ====================================================
+ */
+/* expf(x)
+ * Returns the exponential of x.
+ *
+ * Method: see exp.rs, with the rational approximation of
+ *      R(r**2) = r*(exp(r)+1)/(exp(r)-1)
+ * reduced to a polynomial of degree 2 in r**2.
+ *
+ * Misc. info.
+ *      For IEEE float
+ *          if x >  88.722839 then expf(x) overflows
+ *          if x < -103.972084 then expf(x) underflows
+ */
+
+use crate::scalbnf::scalbnf;
+
+const HALF: [f32; 2] = [0.5, -0.5];
+const LN2HI: f32 = 6.9314575195e-01; /* 0x3f317200 */
+const LN2LO: f32 = 1.4286067653e-06; /* 0x35bfbe8e */
+const INVLN2: f32 = 1.4426950216e+00; /* 0x3fb8aa3b */
+/*
+ * Domain [-0.34568, 0.34568], range ~[-4.278e-9, 4.447e-9]:
+ * |x*(exp(x)+1)/(exp(x)-1) - p(x)| < 2**-27.74
+ */
+const P1: f32 = 1.6666625440e-1; /*  0xaaaa8f.0p-26 */
+const P2: f32 = -2.7667332906e-3; /* -0xb55215.0p-32 */
+
+/// Exponential, base *e* (f32)
+///
+/// Calculate the exponential of `x`, that is, *e* raised to the power `x`
+/// (where *e* is the base of the natural system of logarithms, approximately 2.71828).
+///
+/// ```
+/// # use trig_const::expf;
+/// const EXP_0: f32 = expf(0.0);
+/// assert_eq!(EXP_0, 1.0);
+/// ```
+pub const fn expf(x: f32) -> f32 {
+    nightly_exp!(f32::exp, libm::expf, expf_inner, x)
+}
+
+const fn expf_inner(mut x: f32) -> f32 {
+    let x1p127 = f32::from_bits(0x7f000000); // 0x1p127f === 2 ^ 127
+
+    let mut hx = x.to_bits();
+    let sign = (hx >> 31) as i32; /* sign bit of x */
+    let signb: bool = sign != 0;
+    hx &= 0x7fffffff; /* high word of |x| */
+
+    /* special cases */
+    if hx >= 0x42aeac50 {
+        /* if |x| >= -87.33655f or NaN */
+        if hx > 0x7f800000 {
+            /* NaN */
+            return x;
+        }
+        if (hx >= 0x42b17218) && !signb {
+            /* x >= 88.722839f */
+            /* overflow */
+            x *= x1p127;
+            return x;
+        }
+        if signb && hx >= 0x42cff1b5 {
+            /* x <= -103.972084f */
+            /* underflow */
+            return 0.;
+        }
+    }
+
+    /* argument reduction */
+    let k: i32;
+    let hi: f32;
+    let lo: f32;
+    if hx > 0x3eb17218 {
+        /* if |x| > 0.5 ln2 */
+        if hx > 0x3f851592 {
+            /* if |x| > 1.5 ln2 */
+            k = (INVLN2 * x + HALF[sign as usize]) as i32;
+        } else {
+            k = 1 - sign - sign;
+        }
+        let kf = k as f32;
+        hi = x - kf * LN2HI; /* k*ln2hi is exact here */
+        lo = kf * LN2LO;
+        x = hi - lo;
+    } else if hx > 0x39000000 {
+        /* |x| > 2**-14 */
+        k = 0;
+        hi = x;
+        lo = 0.;
+    } else {
+        /* raise inexact */
+        return 1. + x;
+    }
+
+    /* x is now in primary range */
+    let xx = x * x;
+    let c = x - xx * (P1 + xx * P2);
+    let y = 1. + (x * c / (2. - c) - lo + hi);
+    if k == 0 {
+        y
+    } else {
+        scalbnf(y, k)
+    }
+}
@@ -0,0 +1,149 @@
+//! FIR filter design by the window method
+//!
+//! Each filter is the ideal (brick-wall) impulse response, built from [`sinc`]
+//! terms, truncated to `TAPS` samples, multiplied by a symmetric window and
+//! scaled to unity gain in its passband, as `scipy.signal.firwin` does.
+//! Frequencies are fractions of the sample rate, so the Nyquist frequency is
+//! 0.5. The taps are exactly symmetric, which makes the filters linear-phase
+//! with a delay of `(TAPS - 1) / 2` samples.
+
+use crate::{
+    cospi, sinc,
+    window::{Symmetry, Window},
+};
+
+/// Nyquist frequency, as a fraction of the sample rate
+const NYQUIST: f64 = 0.5;
+
+/// Lowpass filter with cutoff frequency `cutoff`
+///
+/// The gain at DC is 1.
+///
+/// # Panics
+///
+/// Panics if `cutoff` is not strictly between 0 and 0.5, or if `TAPS` is 0.
+///
+/// ```
+/// # use trig_const::{fir_lowpass, window::Window};
+/// // Half-band filter: every other tap is zero
+/// const H: [f64; 31] = fir_lowpass(0.25, Window::Hamming);
+/// assert_eq!(H[1], 0.0);
+/// assert_eq!(H[0], H[30]);
+/// assert!((H.iter().sum::<f64>() - 1.0).abs() < 1e-15);
+/// ```
+pub const fn fir_lowpass<const TAPS: usize>(cutoff: f64, window: Window) -> [f64; TAPS] {
+    assert!(
+        cutoff > 0.0 && cutoff < NYQUIST,
+        "cutoff must be between 0 and 0.5"
+    );
+    design(&[(0.0, cutoff)], window, 0.0)
+}
+
+/// Highpass filter with cutoff frequency `cutoff`
+///
+/// The gain at the Nyquist frequency is 1.
+///
+/// # Panics
+///
+/// Panics if `cutoff` is not strictly between 0 and 0.5, or if `TAPS` is even:
+/// a symmetric filter with an even number of taps has a zero at Nyquist.
+///
+/// ```
+/// # use trig_const::{fir_highpass, window::Window};
+/// const H: [f64; 63] = fir_highpass(0.1, Window::Blackman);
+/// // Blocks DC
+/// assert!(H.iter().sum::<f64>().abs() < 2e-4);
+/// ```
+pub const fn fir_highpass<const TAPS: usize>(cutoff: f64, window: Window) -> [f64; TAPS] {
+    assert!(
+        cutoff > 0.0 && cutoff < NYQUIST,
+        "cutoff must be between 0 and 0.5"
+    );
+    assert!(
+        TAPS % 2 == 1,
+        "a highpass filter needs an odd number of taps"
+    );
+    design(&[(cutoff, NYQUIST)], window, NYQUIST)
+}
+
+/// Bandpass filter passing frequencies between `low` and `high`
+///
+/// The gain at the centre of the band, `(low + high) / 2`, is 1.
+///
+/// # Panics
+///
+/// Panics unless `0 < low < high < 0.5`, or if `TAPS` is 0.
+///
+/// ```
+/// # use trig_const::{fir_bandpass, window::Window};
+/// const H: [f64; 63] = fir_bandpass(0.1, 0.2, Window::Kaiser(8.0));
+/// assert!(H.iter().sum::<f64>().abs() < 1e-5);
+/// ```
+pub const fn fir_bandpass<const TAPS: usize>(low: f64, high: f64, window: Window) -> [f64; TAPS] {
+    assert!(
+        low > 0.0 && low < high && high < NYQUIST,
+        "band edges must satisfy 0 < low < high < 0.5"
+    );
+    design(&[(low, high)], window, 0.5 * (low + high))
+}
+
+/// Bandstop filter rejecting frequencies between `low` and `high`
+///
+/// The gain at DC is 1.
+///
+/// # Panics
+///
+/// Panics unless `0 < low < high < 0.5`, or if `TAPS` is even.
+///
+/// ```
+/// # use trig_const::{fir_bandstop, window::Window};
+/// const H: [f64; 63] = fir_bandstop(0.1, 0.2, Window::Hann);
+/// assert!((H.iter().sum::<f64>() - 1.0).abs() < 1e-15);
+/// ```
+pub const fn fir_bandstop<const TAPS: usize>(low: f64, high: f64, window: Window) -> [f64; TAPS] {
+    assert!(
+        low > 0.0 && low < high && high < NYQUIST,
+        "band edges must satisfy 0 < low < high < 0.5"
+    );
+    assert!(
+        TAPS % 2 == 1,
+        "a bandstop filter needs an odd number of taps"
+    );
+    design(&[(0.0, low), (high, NYQUIST)], window, 0.0)
+}
+
+/// Windowed sum of ideal passbands `(low, high)`, scaled to unity gain at `f0`
+const fn design<const TAPS: usize>(bands: &[(f64, f64)], window: Window, f0: f64) -> [f64; TAPS] {
+    assert!(TAPS > 0, "a filter needs at least one tap");
+    let w: [f64; TAPS] = window.generate(Symmetry::Symmetric);
+    let mut h = [0.0; TAPS];
+    let mut gain = 0.0;
+    let mut n = 0;
+    while n < TAPS {
+        let d = delay(n, TAPS);
+        let mut sum = 0.0;
+        let mut b = 0;
+        while b < bands.len() {
+            let (low, high) = bands[b];
+            /* an ideal lowpass with cutoff f has impulse response 2f sinc(2fd) */
+            sum += 2.0 * high * sinc(2.0 * high * d) - 2.0 * low * sinc(2.0 * low * d);
+            b += 1;
+        }
+        h[n] = sum * w[n];
+        /* frequency response at f0 */
+        gain += h[n] * cospi(2.0 * f0 * d);
+        n += 1;
+    }
+    let mut n = 0;
+    while n < TAPS {
+        h[n] /= gain;
+        n += 1;
+    }
+    h
+}
+
+/// `|n - (taps - 1) / 2|`, the distance of tap `n` from the centre, exactly
+const fn delay(n: usize, taps: usize) -> f64 {
+    let j = if 2 * n + 1 > taps { taps - 1 - n } else { n };
+    (taps - 1 - 2 * j) as f64 * 0.5
+}
@@ -7,12 +7,12 @@
 //! performance seems to be better (based on icount) and it does not seem to experience rounding
 //! errors on i386.
 
-const SIG_BITS: u32 = 52;
+pub(crate) const SIG_BITS: u32 = 52;
 const BITS: u32 = 64;
 const EXP_BITS: u32 = BITS - SIG_BITS - 1;
 const EXP_SAT: u32 = (1 << EXP_BITS) - 1;
 const EXP_BIAS: u32 = EXP_SAT >> 1;
-const SIG_MASK: u64 = 4503599627370495;
+pub(crate) const SIG_MASK: u64 = 4503599627370495;
 
 pub const fn floor(x: f64) -> f64 {
     nightly_exp!(floor, floor_inner, x)
@@ -63,10 +63,10 @@ const fn signed(x: u32) -> i32 {
     x as i32
 }
 
-const fn unsigned(x: i32) -> u32 {
+pub(crate) const fn unsigned(x: i32) -> u32 {
     x as u32
 }
 
-const fn exp_unbiased(x: f64) -> i32 {
+pub(crate) const fn exp_unbiased(x: f64) -> i32 {
     signed(ex(x)) - EXP_BIAS as i32
 }
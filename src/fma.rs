@@ -0,0 +1,292 @@
+/* SPDX-License-Identifier: MIT */
+/* origin: musl src/math/fma.c. Ported to generic Rust algorithm in 2025, TG. */
+
+//! Fused multiply-add that works without a larger float type, by forming the
+//! exact product of the significands as a 128-bit integer.
+
+use crate::scalbn;
+
+const BITS: u32 = 64;
+const SIG_BITS: u32 = 52;
+const EXP_BITS: u32 = BITS - SIG_BITS - 1;
+const EXP_SAT: u32 = (1 << EXP_BITS) - 1;
+const EXP_BIAS: u32 = EXP_SAT >> 1;
+const SIG_MASK: u64 = (1 << SIG_BITS) - 1;
+const IMPLICIT_BIT: u64 = 1 << SIG_BITS;
+
+/// Fused multiply-add, `x * y + z` with a single rounding
+///
+/// The product is computed exactly and rounded only once, after the addition.
+///
+/// ```
+/// # use trig_const::fma;
+/// const ERR: f64 = fma(0.1, 10.0, -1.0);
+/// assert_eq!(ERR, 5.551115123125783e-17);
+/// ```
+pub const fn fma(x: f64, y: f64, z: f64) -> f64 {
+    nightly_exp!(f64::mul_add, libm::fma, fma_inner, x, y, z)
+}
+
+const fn fma_inner(x: f64, y: f64, z: f64) -> f64 {
+    // Normalize such that the top of the mantissa is zero and we have a guard bit.
+    let nx = Norm::from_float(x);
+    let ny = Norm::from_float(y);
+    let nz = Norm::from_float(z);
+
+    if nx.is_zero_nan_inf() || ny.is_zero_nan_inf() {
+        // Value will overflow, defer to non-fused operations.
+        return x * y + z;
+    }
+
+    if nz.is_zero_nan_inf() {
+        if nz.is_zero() {
+            // Empty add component means we only need to multiply.
+            return x * y;
+        }
+        // `z` is NaN or infinity, which sets the result.
+        return z;
+    }
+
+    // multiply: r = x * y
+    let zhi: u64;
+    let zlo: u64;
+    let product = nx.m as u128 * ny.m as u128;
+    let mut rlo = product as u64;
+    let mut rhi = (product >> BITS) as u64;
+
+    // Exponent result of multiplication
+    let mut e: i32 = nx.e + ny.e;
+    // Needed shift to align `z` to the multiplication result
+    let mut d: i32 = nz.e - e;
+    let sbits = BITS as i32;
+
+    // Scale `z`. Shift `z <<= kz`, `r >>= kr`, so `kz+kr == d`, set `e = e+kr` (== ez-kz)
+    if d > 0 {
+        // The magnitude of `z` is larger than `x * y`
+        if d < sbits {
+            // Maximum shift of one `BITS` means shifted `z` will fit into `2 * BITS`. Shift
+            // it into `(zhi, zlo)`. No exponent adjustment necessary.
+            zlo = nz.m << d;
+            zhi = nz.m >> (sbits - d);
+        } else {
+            // Shift larger than `sbits`, `z` only needs the top half `zhi`. Place it there (acts
+            // as a shift by `sbits`).
+            zlo = 0;
+            zhi = nz.m;
+            d -= sbits;
+
+            // `z`'s exponent is large enough that it now needs to be taken into account.
+            e = nz.e - sbits;
+
+            if d == 0 {
+                // Exactly `sbits`, nothing to do
+            } else if d < sbits {
+                // Remaining shift fits within `sbits`. Leave `z` in place, shift `x * y`
+                rlo = (rhi << (sbits - d)) | (rlo >> d);
+                // Set the sticky bit
+                rlo |= ((rlo << (sbits - d)) != 0) as u64;
+                rhi >>= d;
+            } else {
+                // `z`'s magnitude is enough that `x * y` is irrelevant. It was nonzero, so set
+                // the sticky bit.
+                rlo = 1;
+                rhi = 0;
+            }
+        }
+    } else {
+        // `z`'s magnitude once shifted fits entirely within `zlo`
+        zhi = 0;
+        d = -d;
+        if d == 0 {
+            // No shift needed
+            zlo = nz.m;
+        } else if d < sbits {
+            // Shift s.t. `nz.m` fits into `zlo`
+            let sticky = ((nz.m << (sbits - d)) != 0) as u64;
+            zlo = (nz.m >> d) | sticky;
+        } else {
+            // Would be entirely shifted out, only set the sticky bit
+            zlo = 1;
+        }
+    }
+
+    /* addition */
+
+    let mut neg = nx.neg ^ ny.neg;
+    let samesign: bool = !neg ^ nz.neg;
+    let mut rhi_nonzero = true;
+
+    if samesign {
+        // r += z
+        rlo = rlo.wrapping_add(zlo);
+        rhi += zhi + (rlo < zlo) as u64;
+    } else {
+        // r -= z
+        let (res, borrow) = rlo.overflowing_sub(zlo);
+        rlo = res;
+        rhi = rhi.wrapping_sub(zhi.wrapping_add(borrow as u64));
+        if (rhi >> (BITS - 1)) != 0 {
+            rlo = (rlo as i64).wrapping_neg() as u64;
+            rhi = (rhi as i64).wrapping_neg() as u64 - (rlo != 0) as u64;
+            neg = !neg;
+        }
+        rhi_nonzero = rhi != 0;
+    }
+
+    /* Construct result */
+
+    // Shift result into `rhi`, left-aligned. Last bit is sticky
+    if rhi_nonzero {
+        // `d` > 0, need to shift both `rhi` and `rlo` into result
+        e += sbits;
+        d = rhi.leading_zeros() as i32 - 1;
+        rhi = (rhi << d) | (rlo >> (sbits - d));
+        // Update sticky
+        rhi |= ((rlo << d) != 0) as u64;
+    } else if rlo != 0 {
+        // `rhi` is zero, `rlo` is the entire result and needs to be shifted
+        d = rlo.leading_zeros() as i32 - 1;
+        if d < 0 {
+            // Shift and set sticky
+            rhi = (rlo >> 1) | (rlo & 1);
+        } else {
+            rhi = rlo << d;
+        }
+    } else {
+        // exact +/- 0.0
+        return x * y + z;
+    }
+
+    e -= d;
+
+    // Use int->float conversion to populate the significand.
+    // i is in [1 << (BITS - 2), (1 << (BITS - 1)) - 1]
+    let mut i: i64 = rhi as i64;
+
+    if neg {
+        i = -i;
+    }
+
+    // `|r|` is in `[0x1p62,0x1p63]`
+    let mut r: f64 = i as f64;
+
+    /* Account for subnormal and rounding */
+
+    // Unbiased exponent for the maximum value of `r`
+    let max_pow = BITS - 1 + EXP_BIAS;
+
+    if e < -(max_pow as i32 - 2) {
+        // Result is subnormal before rounding
+        if e == -(max_pow as i32 - 1) {
+            let mut c = f64::from_bits((max_pow as u64) << SIG_BITS);
+            if neg {
+                c = -c;
+            }
+
+            if r == c {
+                // Min normal after rounding,
+                return if neg {
+                    -f64::MIN_POSITIVE
+                } else {
+                    f64::MIN_POSITIVE
+                };
+            }
+
+            if (rhi << (SIG_BITS + 1)) != 0 {
+                // Account for truncated bits. One bit will be lost in the `scalbn` call, add
+                // another top bit to avoid double rounding if inexact.
+                let iu: u64 = (rhi >> 1) | (rhi & 1) | (1 << (BITS - 2));
+                i = iu as i64;
+
+                if neg {
+                    i = -i;
+                }
+
+                r = i as f64;
+
+                // Remove the top bit
+                r = 2.0 * r - c;
+            }
+        } else {
+            // Only round once when scaled
+            d = EXP_BITS as i32 - 1;
+            let sticky = (rhi << (BITS as i32 - d) != 0) as u64;
+            i = (((rhi >> d) | sticky) << d) as i64;
+
+            if neg {
+                i = -i;
+            }
+
+            r = i as f64;
+        }
+    }
+
+    // Use our exponent to scale the final value.
+    scalbn(r, e)
+}
+
+/// Representation of an `f64` that has handled subnormals.
+#[derive(Clone, Copy, Debug)]
+struct Norm {
+    /// Normalized significand with one guard bit, unsigned.
+    m: u64,
+    /// Exponent of the mantissa such that `m * 2^e = x`. Accounts for the shift in the mantissa
+    /// and the guard bit; that is, 1.0 will normalize as `m = 1 << 53` and `e = -53`.
+    e: i32,
+    neg: bool,
+}
+
+impl Norm {
+    /// Unbias the exponent and account for the mantissa's precision, including the guard bit.
+    const EXP_UNBIAS: u32 = EXP_BIAS + SIG_BITS + 1;
+
+    /// Values greater than this had a saturated exponent (infinity or NaN), OR were zero and we
+    /// adjusted the exponent such that it exceeds this threshold.
+    const ZERO_INF_NAN: u32 = EXP_SAT - Self::EXP_UNBIAS;
+
+    const fn from_float(x: f64) -> Self {
+        let mut ix = x.to_bits();
+        let mut e = ex(x) as i32;
+        let neg = x.is_sign_negative();
+        if e == 0 {
+            // Normalize subnormals by multiplication
+            let scale_i = BITS - 1;
+            let scale_f = f64::from_bits(((scale_i + EXP_BIAS) as u64) << SIG_BITS);
+            let scaled = x * scale_f;
+            ix = scaled.to_bits();
+            e = ex(scaled) as i32;
+            e = if e == 0 {
+                // If the exponent is still zero, the input was zero. Artificially set this value
+                // such that the final `e` will exceed `ZERO_INF_NAN`.
+                1 << EXP_BITS
+            } else {
+                // Otherwise, account for the scaling we just did.
+                e - scale_i as i32
+            };
+        }
+
+        e -= Self::EXP_UNBIAS as i32;
+
+        // Absolute value, set the implicit bit, and shift to create a guard bit
+        ix &= SIG_MASK;
+        ix |= IMPLICIT_BIT;
+        ix <<= 1;
+
+        Self { m: ix, e, neg }
+    }
+
+    /// True if the value was zero, infinity, or NaN.
+    const fn is_zero_nan_inf(self) -> bool {
+        self.e >= Self::ZERO_INF_NAN as i32
+    }
+
+    /// True if the value was zero.
+    const fn is_zero(self) -> bool {
+        // The only exponent that strictly exceeds this value is our sentinel value for zero.
+        self.e > Self::ZERO_INF_NAN as i32
+    }
+}
+
+const fn ex(x: f64) -> u32 {
+    (x.to_bits() >> SIG_BITS) as u32 & EXP_SAT
+}
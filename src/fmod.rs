@@ -0,0 +1,100 @@
+/* origin: musl src/math/fmod.c */
+
+/// Floating-point remainder of `x / y`, rounded toward zero
+///
+/// The result is exactly `x - n * y`, where `n` is `x / y` truncated to an
+/// integer, and has the sign of `x`. This matches the `%` operator.
+///
+/// ```
+/// # use trig_const::fmod;
+/// const FMOD: f64 = fmod(7.5, 2.0);
+/// assert_eq!(FMOD, 1.5);
+/// const FMOD_NEG: f64 = fmod(-7.5, 2.0);
+/// assert_eq!(FMOD_NEG, -1.5);
+/// ```
+pub const fn fmod(x: f64, y: f64) -> f64 {
+    nightly_exp!(libm::fmod, libm::fmod, fmod_inner, x, y)
+}
+
+const fn fmod_inner(x: f64, y: f64) -> f64 {
+    let ux: u64 = x.to_bits();
+    let mut uy: u64 = y.to_bits();
+    let mut ex = ((ux >> 52) & 0x7ff) as i32;
+    let mut ey = ((uy >> 52) & 0x7ff) as i32;
+    let sx = ux >> 63;
+    let mut i: u64;
+    let mut uxi: u64 = ux;
+
+    if (uy << 1) == 0 || y.is_nan() || ex == 0x7ff {
+        /* nan propagates; fmod(x, 0) and fmod(inf, y) are nan */
+        return if x.is_nan() || y.is_nan() {
+            x + y
+        } else {
+            f64::NAN
+        };
+    }
+    if (uxi << 1) <= (uy << 1) {
+        if (uxi << 1) == (uy << 1) {
+            return 0.0 * x;
+        }
+        return x;
+    }
+
+    /* normalize x and y */
+    if ex == 0 {
+        i = uxi << 12;
+        while (i >> 63) == 0 {
+            ex -= 1;
+            i <<= 1;
+        }
+        uxi <<= -ex + 1;
+    } else {
+        uxi &= (!0) >> 12;
+        uxi |= 1 << 52;
+    }
+    if ey == 0 {
+        i = uy << 12;
+        while (i >> 63) == 0 {
+            ey -= 1;
+            i <<= 1;
+        }
+        uy <<= -ey + 1;
+    } else {
+        uy &= (!0) >> 12;
+        uy |= 1 << 52;
+    }
+
+    /* x mod y */
+    while ex > ey {
+        i = uxi.wrapping_sub(uy);
+        if (i >> 63) == 0 {
+            if i == 0 {
+                return 0.0 * x;
+            }
+            uxi = i;
+        }
+        uxi <<= 1;
+        ex -= 1;
+    }
+    i = uxi.wrapping_sub(uy);
+    if (i >> 63) == 0 {
+        if i == 0 {
+            return 0.0 * x;
+        }
+        uxi = i;
+    }
+    while (uxi >> 52) == 0 {
+        uxi <<= 1;
+        ex -= 1;
+    }
+
+    /* scale result */
+    if ex > 0 {
+        uxi -= 1 << 52;
+        uxi |= (ex as u64) << 52;
+    } else {
+        uxi >>= -ex + 1;
+    }
+    uxi |= sx << 63;
+    f64::from_bits(uxi)
+}
@@ -0,0 +1,39 @@
+/* origin: musl src/math/frexp.c */
+
+/// Split `x` into a normalized fraction and a power of two
+///
+/// Returns `(m, e)` with `x == m * 2^e` and `0.5 <= |m| < 1`. Zero, infinities
+/// and NaN are returned unchanged with an exponent of 0. Subnormal `x` is
+/// normalized, so its exponent can be as low as -1073.
+///
+/// ```
+/// # use trig_const::frexp;
+/// const FREXP: (f64, i32) = frexp(12.0);
+/// assert_eq!(FREXP, (0.75, 4));
+/// const FREXP_SUBNORMAL: (f64, i32) = frexp(5e-324);
+/// assert_eq!(FREXP_SUBNORMAL, (0.5, -1073));
+/// ```
+pub const fn frexp(x: f64) -> (f64, i32) {
+    nightly_exp!(libm::frexp, libm::frexp, frexp_inner, x)
+}
+
+const fn frexp_inner(x: f64) -> (f64, i32) {
+    let mut y = x.to_bits();
+    let ee = ((y >> 52) & 0x7ff) as i32;
+
+    if ee == 0 {
+        if x != 0.0 {
+            let x1p64 = f64::from_bits(0x43f0000000000000);
+            let (x, e) = frexp_inner(x * x1p64);
+            return (x, e - 64);
+        }
+        return (x, 0);
+    } else if ee == 0x7ff {
+        return (x, 0);
+    }
+
+    let e = ee - 0x3fe;
+    y &= 0x800fffffffffffff;
+    y |= 0x3fe0000000000000;
+    (f64::from_bits(y), e)
+}
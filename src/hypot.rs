@@ -0,0 +1,82 @@
+/* origin: musl src/math/hypot.c */
+
+use crate::sqrt;
+
+const SPLIT: f64 = 134217728. + 1.; // 0x1p27 + 1 === (2 ^ 27) + 1
+
+/// `x * x` as an exact sum `hi + lo`
+const fn sq(x: f64) -> (f64, f64) {
+    let xc: f64 = x * SPLIT;
+    let xh: f64 = x - xc + xc;
+    let xl: f64 = x - xh;
+    let hi = x * x;
+    let lo = xh * xh - hi + 2. * xh * xl + xl * xl;
+    (hi, lo)
+}
+
+/// Euclidean distance, `sqrt(x * x + y * y)`
+///
+/// Computed without undue overflow or underflow, with an error below one ulp.
+/// `hypot(±∞, y)` is `+∞`, even if `y` is NaN.
+///
+/// ```
+/// # use trig_const::hypot;
+/// const HYPOT_3_4: f64 = hypot(3.0, 4.0);
+/// assert_eq!(HYPOT_3_4, 5.0);
+/// const HYPOT_LARGE: f64 = hypot(3e300, 4e300);
+/// assert_eq!(HYPOT_LARGE, 5e300);
+/// ```
+pub const fn hypot(x: f64, y: f64) -> f64 {
+    nightly_exp!(hypot, hypot_inner, x, y)
+}
+
+const fn hypot_inner(mut x: f64, mut y: f64) -> f64 {
+    let x1p700 = f64::from_bits(0x6bb0000000000000); // 0x1p700 === 2 ^ 700
+    let x1p_700 = f64::from_bits(0x1430000000000000); // 0x1p-700 === 2 ^ -700
+
+    let mut uxi = x.to_bits();
+    let mut uyi = y.to_bits();
+
+    /* arrange |x| >= |y| */
+    uxi &= -1i64 as u64 >> 1;
+    uyi &= -1i64 as u64 >> 1;
+    if uxi < uyi {
+        let uti = uxi;
+        uxi = uyi;
+        uyi = uti;
+    }
+
+    /* special cases */
+    let ex: i64 = (uxi >> 52) as i64;
+    let ey: i64 = (uyi >> 52) as i64;
+    x = f64::from_bits(uxi);
+    y = f64::from_bits(uyi);
+    /* note: hypot(inf,nan) == inf */
+    if ey == 0x7ff {
+        return y;
+    }
+    if ex == 0x7ff || uyi == 0 {
+        return x;
+    }
+    /* note: hypot(x,y) ~= x + y*y/x/2 with inexact for small y/x */
+    /* 64 difference is enough for ld80 double_t */
+    if ex - ey > 64 {
+        return x + y;
+    }
+
+    /* precise sqrt argument in nearest rounding mode without overflow */
+    /* xh*xh must not overflow and xl*xl must not underflow in sq */
+    let mut z: f64 = 1.;
+    if ex > 0x3ff + 510 {
+        z = x1p700;
+        x *= x1p_700;
+        y *= x1p_700;
+    } else if ey < 0x3ff - 450 {
+        z = x1p_700;
+        x *= x1p700;
+        y *= x1p700;
+    }
+    let (hx, lx) = sq(x);
+    let (hy, ly) = sq(y);
+    z * sqrt(ly + lx + hy + hx)
+}
@@ -0,0 +1,49 @@
+/* origin: musl src/math/ilogb.c */
+
+/// Returned by [`ilogb`] for NaN
+pub const FP_ILOGBNAN: i32 = -1 - 0x7fffffff;
+/// Returned by [`ilogb`] for zero
+pub const FP_ILOGB0: i32 = FP_ILOGBNAN;
+
+/// Unbiased binary exponent of `x`, as an integer
+///
+/// Subnormals report their true exponent, down to -1074. `ilogb(0)` is
+/// [`FP_ILOGB0`], `ilogb(NaN)` is [`FP_ILOGBNAN`] and `ilogb(±∞)` is `i32::MAX`.
+///
+/// ```
+/// # use trig_const::ilogb;
+/// const ILOGB: i32 = ilogb(10.0);
+/// assert_eq!(ILOGB, 3);
+/// const ILOGB_SUBNORMAL: i32 = ilogb(5e-324);
+/// assert_eq!(ILOGB_SUBNORMAL, -1074);
+/// ```
+pub const fn ilogb(x: f64) -> i32 {
+    nightly_exp!(libm::ilogb, libm::ilogb, ilogb_inner, x)
+}
+
+pub(crate) const fn ilogb_inner(x: f64) -> i32 {
+    let mut i: u64 = x.to_bits();
+    let e = ((i >> 52) & 0x7ff) as i32;
+
+    if e == 0 {
+        i <<= 12;
+        if i == 0 {
+            return FP_ILOGB0;
+        }
+        /* subnormal x */
+        let mut e = -0x3ff;
+        while (i >> 63) == 0 {
+            e -= 1;
+            i <<= 1;
+        }
+        e
+    } else if e == 0x7ff {
+        if (i << 12) != 0 {
+            FP_ILOGBNAN
+        } else {
+            i32::MAX
+        }
+    } else {
+        e - 0x3ff
+    }
+}
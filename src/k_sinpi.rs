@@ -0,0 +1,52 @@
+// origin: FreeBSD /usr/src/lib/msun/src/k_sinpi.h
+//
+// ====================================================
+// Copyright (c) 2017 Steven G. Kargl
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions
+// are met:
+// 1. Redistributions of source code must retain the above copyright
+//    notice unmodified, this list of conditions, and the following
+//    disclaimer.
+// 2. Redistributions in binary form must reproduce the above copyright
+//    notice, this list of conditions and the following disclaimer in the
+//    documentation and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+// IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+// OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+// IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+// INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+// NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+// DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+// THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+// (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+// THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+// ====================================================
+
+use crate::k_sin::k_sin;
+
+/// pi rounded to 26 bits, so that a product with a 24-bit value is exact
+pub(crate) const PI_HI: f64 = 3.1415926814079285e+00; /* 0x400921fb, 0x58000000 */
+/// pi - PI_HI
+pub(crate) const PI_LO: f64 = -2.7818135457705033e-08; /* 0xbe5dde97, 0x3dcb3b3a */
+
+/// pi * x as a double-double, for x in [0, 0.25]
+pub(crate) const fn mul_pi(x: f64) -> (f64, f64) {
+    let hi = x as f32 as f64;
+    let lo = x - hi;
+    let lo = lo * (PI_LO + PI_HI) + hi * PI_LO;
+    let hi = hi * PI_HI;
+    /* _2sumF(hi, lo) */
+    let s = hi + lo;
+    (s, lo - (s - hi))
+}
+
+// The basic kernel for x in [0,0.25].  To use the kernel for sin(x), the
+// argument to k_sinpi() must be multiplied by pi.
+pub(crate) const fn k_sinpi(x: f64) -> f64 {
+    let (hi, lo) = mul_pi(x);
+    k_sin(hi, lo, 1)
+}
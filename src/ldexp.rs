@@ -0,0 +1,16 @@
+/* origin: musl src/math/ldexp.c */
+
+use crate::scalbn;
+
+/// Multiply `x` by 2 raised to the power `n`
+///
+/// The same as [`scalbn`]: exact unless the result overflows or underflows.
+///
+/// ```
+/// # use trig_const::ldexp;
+/// const LDEXP: f64 = ldexp(0.75, 3);
+/// assert_eq!(LDEXP, 6.0);
+/// ```
+pub const fn ldexp(x: f64, n: i32) -> f64 {
+    scalbn(x, n)
+}
@@ -16,20 +16,82 @@ mod asinh;
 mod atan;
 mod atan2;
 mod atanh;
+mod bessel_i;
+mod cbrt;
+mod ceil;
+pub mod complex;
 mod cos;
+mod cosf;
+mod cosh;
+mod cospi;
+mod dd;
+mod degrees;
+mod erf;
+mod erf_inv;
 mod exp;
+mod exp10;
+mod exp2;
+mod expf;
+mod expi;
+mod expm1;
+mod expo2;
+mod fir;
 mod floor;
+mod fma;
+mod fmod;
+mod frexp;
+mod hypot;
+mod ilogb;
+mod j0;
+mod j1;
+mod jn;
 mod k_cos;
+mod k_cosf;
+mod k_cospi;
 mod k_sin;
+mod k_sinf;
+mod k_sinpi;
 pub(crate) mod k_tan;
+mod k_tanf;
+mod ldexp;
+mod lgamma;
 mod ln;
-pub(crate) mod log1p;
+mod lnf;
+mod log10;
+mod log1p;
+mod log2;
+mod logb;
+mod modf;
+mod nextafter;
 mod pow;
+mod powf;
+pub mod reduce;
 mod rem_pio2;
 mod rem_pio2_large;
-pub(crate) mod scalbn;
+mod rem_pio2f;
+mod remainder;
+mod remquo;
+mod rint;
+mod round;
+mod scalbn;
+mod scalbnf;
 mod sin;
+mod sinc;
+mod sincos;
+mod sinf;
+mod sinh;
+mod sinpi;
+mod sqrt;
+mod sqrtf;
 mod tan;
+mod tanf;
+mod tanh;
+mod tanpi;
+mod tgamma;
+mod trunc;
+mod twiddle;
+mod ulp;
+pub mod window;
 pub use acos::acos;
 pub use acosh::acosh;
 pub use asin::asin;
@@ -37,16 +99,65 @@ pub use asinh::asinh;
 pub use atan::atan;
 pub use atan2::atan2;
 pub use atanh::atanh;
+pub use bessel_i::{bessel_i0, bessel_i1, i0e, i1e};
+pub use cbrt::cbrt;
+pub use ceil::ceil;
 pub use cos::cos;
+pub use cosf::cosf;
+pub use cosh::cosh;
+pub use cospi::cospi;
+pub use degrees::{acosd, asind, atan2d, atand, cosd, sind, tand};
+pub use erf::{erf, erfc};
+pub use erf_inv::{erf_inv, erfc_inv};
 pub use exp::exp;
+pub use exp10::exp10;
+pub use exp2::exp2;
+pub use expf::expf;
+pub use expi::expi;
+pub use expm1::expm1;
+pub use fir::{fir_bandpass, fir_bandstop, fir_highpass, fir_lowpass};
 pub use floor::floor;
+pub use fma::fma;
+pub use fmod::fmod;
+pub use frexp::frexp;
+pub use hypot::hypot;
+pub use ilogb::{ilogb, FP_ILOGB0, FP_ILOGBNAN};
+pub use j0::{j0, y0};
+pub use j1::{j1, y1};
+pub use jn::{jn, yn};
+pub use ldexp::ldexp;
+pub use lgamma::{lgamma, lgamma_r};
 pub use ln::ln;
+pub use lnf::lnf;
+pub use log10::log10;
+pub use log1p::log1p;
+pub use log2::log2;
+pub use logb::logb;
+pub use modf::modf;
+pub use nextafter::{next_down, next_up, nextafter};
 pub use pow::pow;
+pub use powf::powf;
+pub use remainder::remainder;
+pub use remquo::remquo;
+pub use rint::{nearbyint, rint, round_ties_even};
+pub use round::round;
+pub use scalbn::scalbn;
 pub use sin::sin;
+pub use sinc::{sinc, sinc_unnormalized};
+pub use sincos::sincos;
+pub use sinf::sinf;
+pub use sinh::sinh;
+pub use sinpi::sinpi;
+pub use sqrt::sqrt;
+pub use sqrtf::sqrtf;
 pub use tan::tan;
-
-/// Number of sum iterations for Taylor series
-const TAYLOR_SERIES_SUMS: usize = 16;
+pub use tanf::tanf;
+pub use tanh::tanh;
+pub use tanpi::tanpi;
+pub use tgamma::tgamma;
+pub use trunc::trunc;
+pub use twiddle::{half_twiddles, inverse_twiddles, quarter_twiddles, twiddles};
+pub use ulp::{ulp, ulp_distance};
 
 /// Cotangent
 ///
@@ -100,88 +211,71 @@ pub const fn csc(x: f64) -> f64 {
     }
 }
 
-/// Hyperbolic Sine
+/// Arcsine divided by π, asin(x)/π
 ///
 /// ```
-/// # use trig_const::sinh;
-/// const SINH_0: f64 = sinh(0.0);
-/// assert_eq!(SINH_0, 0.0);
+/// # use trig_const::asinpi;
+/// const ASINPI_1: f64 = asinpi(1.0);
+/// assert_eq!(ASINPI_1, 0.5);
 /// ```
-pub const fn sinh(x: f64) -> f64 {
-    nightly_exp!(sinh, sinh_inner, x)
-}
-
-const fn sinh_inner(x: f64) -> f64 {
-    (exp(x) - exp(-x)) / 2.0
+pub const fn asinpi(x: f64) -> f64 {
+    asin(x) / core::f64::consts::PI
 }
 
-/// Hyperbolic Cosine
+/// Arccosine divided by π, acos(x)/π
 ///
 /// ```
-/// # use trig_const::cosh;
-/// const COSH_0: f64 = cosh(0.0);
-/// assert_eq!(COSH_0, 1.0);
+/// # use trig_const::acospi;
+/// const ACOSPI_NEG_1: f64 = acospi(-1.0);
+/// assert_eq!(ACOSPI_NEG_1, 1.0);
 /// ```
-pub const fn cosh(x: f64) -> f64 {
-    nightly_exp!(cosh, cosh_inner, x)
+pub const fn acospi(x: f64) -> f64 {
+    acos(x) / core::f64::consts::PI
 }
 
-const fn cosh_inner(x: f64) -> f64 {
-    (exp(x) + exp(-x)) / 2.0
+/// Arctangent divided by π, atan(x)/π
+///
+/// ```
+/// # use trig_const::atanpi;
+/// const ATANPI_1: f64 = atanpi(1.0);
+/// assert_eq!(ATANPI_1, 0.25);
+/// ```
+pub const fn atanpi(x: f64) -> f64 {
+    atan(x) / core::f64::consts::PI
 }
 
-/// x^pow
-pub const fn expi(x: f64, mut pow: isize) -> f64 {
-    let mut o = 1.0;
-
-    while pow > 0 {
-        o *= x;
-        pow -= 1;
-    }
-    while pow < 0 {
-        o /= x;
-        pow += 1;
-    }
-
-    o
+/// Two-argument arctangent divided by π, atan2(y, x)/π
+///
+/// ```
+/// # use trig_const::atan2pi;
+/// const ATAN2PI_1_NEG_1: f64 = atan2pi(1.0, -1.0);
+/// assert_eq!(ATAN2PI_1_NEG_1, 0.75);
+/// ```
+pub const fn atan2pi(y: f64, x: f64) -> f64 {
+    atan2(y, x) / core::f64::consts::PI
 }
 
 /// Factorial (x!)
-pub const fn factorial(mut x: f64) -> f64 {
-    if x == 0.0 || x == 1.0 {
-        1.0
-    } else {
-        let mut s = 1.0;
-        while x > 1.0 {
-            s *= x;
-            x -= 1.0;
+///
+/// Defined as Γ(x + 1), so non-integer arguments are also supported. Integers up to
+/// 170 come from an exact table, larger ones overflow to infinity, and negative
+/// integers are NaN.
+///
+/// ```
+/// # use trig_const::factorial;
+/// const FACTORIAL_20: f64 = factorial(20.0);
+/// assert_eq!(FACTORIAL_20, 2432902008176640000.0);
+/// ```
+pub const fn factorial(x: f64) -> f64 {
+    if x == floor(x) {
+        if x < 0.0 {
+            return f64::NAN;
+        }
+        if x < tgamma::FACT.len() as f64 {
+            return tgamma::FACT[x as usize];
         }
-        s
-    }
-}
-
-/// Const sqrt function using Newton's method
-pub const fn sqrt(x: f64) -> f64 {
-    nightly_exp!(sqrt, sqrt_inner, x)
-}
-
-const fn sqrt_inner(x: f64) -> f64 {
-    if x.is_nan() || x < 0.0 {
-        return f64::NAN;
-    } else if x.is_infinite() || x == 0.0 {
-        return x;
-    }
-
-    // Use Newton's method for sqrt calculation
-    let mut current_guess = 1.0;
-
-    let mut i = 0;
-    while i < TAYLOR_SERIES_SUMS {
-        current_guess = 0.5 * (current_guess + x / current_guess);
-        i += 1;
     }
-
-    current_guess
+    tgamma(x + 1.0)
 }
 
 pub const fn fabs(x: f64) -> f64 {
@@ -212,7 +306,11 @@ const fn get_low_word(x: f64) -> u32 {
 mod tests {
     use core::f64::consts::{E, PI};
 
-    use crate::{cos, cosh, exp, expi, factorial, ln, sin, sinh, sqrt};
+    use crate::{
+        acosd, acospi, asind, asinpi, atan2d, atan2pi, atand, atanpi, ceil, cos, cosd, cosh, cospi,
+        exp, exp10, exp2, expi, factorial, floor, ln, log10, log2, nearbyint, rint, round,
+        round_ties_even, sin, sind, sinh, sinpi, sqrt, tand, tanh, tanpi, trunc,
+    };
 
     macro_rules! float_eq {
         ($lhs:expr, $rhs:expr) => {
@@ -228,6 +326,12 @@ mod tests {
         assert_eq!(factorial(3.0), 6.0);
         assert_eq!(factorial(4.0), 24.0);
         assert_eq!(factorial(5.0), 120.0);
+        assert_eq!(factorial(170.0), 7.257415615307999e306);
+        assert_eq!(factorial(171.0), f64::INFINITY);
+        assert!(factorial(-1.0).is_nan());
+        assert!(factorial(-3.0).is_nan());
+        float_eq!(factorial(0.5), 0.886226925452758);
+        float_eq!(factorial(-0.5), core::f64::consts::PI.sqrt());
     }
 
     #[test]
@@ -236,6 +340,34 @@ mod tests {
         assert_eq!(expi(2.0, 4), 16.0);
         assert_eq!(expi(2.0, 5), 32.0);
         assert_eq!(expi(3.0, 3), 27.0);
+        assert_eq!(expi(3.0, 20), 3486784401.0);
+        assert_eq!(expi(-2.0, 3), -8.0);
+        assert_eq!(expi(2.0, -2), 0.25);
+        assert_eq!(expi(2.0, 1023), f64::MAX / (2.0 - f64::EPSILON));
+        assert_eq!(expi(2.0, 1024), f64::INFINITY);
+        assert_eq!(expi(2.0, -1074), 5e-324);
+        assert_eq!(expi(0.5, 1074), 5e-324);
+        assert_eq!(expi(2.0, -1076), 0.0);
+        assert_eq!(expi(1e-300, -1), 1.0 / 1e-300);
+        assert_eq!(expi(1.0000001, isize::MAX), f64::INFINITY);
+        assert_eq!(expi(0.9999999, isize::MAX), 0.0);
+        assert_eq!(expi(-1.0, isize::MAX), -1.0);
+        assert_eq!(expi(-1.0, isize::MIN), 1.0);
+        assert_eq!(expi(f64::NAN, 0), 1.0);
+        assert!(expi(f64::NAN, 1).is_nan());
+    }
+
+    #[test]
+    fn test_expi_zero() {
+        // pow.rs special cases 12-15
+        assert_eq!(expi(0.0, -3), f64::INFINITY);
+        assert_eq!(expi(0.0, -2), f64::INFINITY);
+        assert_eq!(expi(-0.0, -2), f64::INFINITY);
+        assert_eq!(expi(-0.0, -3), f64::NEG_INFINITY);
+        assert_eq!(expi(-0.0, 3).to_bits(), (-0.0f64).to_bits());
+        assert_eq!(expi(-0.0, 2).to_bits(), 0.0f64.to_bits());
+        assert_eq!(expi(f64::NEG_INFINITY, 3), f64::NEG_INFINITY);
+        assert_eq!(expi(f64::NEG_INFINITY, -3).to_bits(), (-0.0f64).to_bits());
     }
 
     #[test]
@@ -284,6 +416,193 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_tanh() {
+        for x in [0.0, 0.5, 1.0, 1.5, 2.0, 2.5] {
+            float_eq!(tanh(x), x.tanh());
+        }
+    }
+
+    #[test]
+    fn test_hyperbolic_special_values() {
+        assert!(sinh(-0.0).is_sign_negative());
+        assert!(tanh(-0.0).is_sign_negative());
+        assert_eq!(sinh(1e-300), 1e-300);
+        assert_eq!(tanh(1e-300), 1e-300);
+        assert_eq!(sinh(1000.0), f64::INFINITY);
+        assert_eq!(sinh(-1000.0), f64::NEG_INFINITY);
+        assert_eq!(cosh(-1000.0), f64::INFINITY);
+        assert_eq!(tanh(1000.0), 1.0);
+        assert_eq!(tanh(-1000.0), -1.0);
+        assert!(tanh(f64::NAN).is_nan());
+        // exp(710) overflows on its own, sinh/cosh must not
+        assert!(sinh(710.0).is_finite());
+        assert!(cosh(-710.0).is_finite());
+    }
+
+    #[test]
+    fn test_pi_scaled_exact() {
+        for n in -8..=8 {
+            let n = n as f64;
+            assert_eq!(sinpi(n).to_bits(), (0.0f64).copysign(n).to_bits());
+            assert_eq!(
+                sinpi(n + 0.5),
+                if n.rem_euclid(2.0) == 0.0 { 1.0 } else { -1.0 }
+            );
+            assert_eq!(cospi(n + 0.5), 0.0);
+            assert_eq!(cospi(n), if n.rem_euclid(2.0) == 0.0 { 1.0 } else { -1.0 });
+            assert_eq!(tanpi(n), 0.0);
+            assert_eq!(tanpi(n + 0.25), 1.0);
+            assert_eq!(tanpi(n - 0.25), -1.0);
+        }
+        assert_eq!(sinpi(-0.0).to_bits(), (-0.0f64).to_bits());
+        assert_eq!(sinpi(-2.0).to_bits(), (-0.0f64).to_bits());
+        // sinpi(+n) = +0 and sinpi(-n) = -0, whatever the parity of n
+        assert_eq!(sinpi(1.0).to_bits(), 0.0f64.to_bits());
+        assert_eq!(sinpi(3.0).to_bits(), 0.0f64.to_bits());
+        assert_eq!(sinpi(-1.0).to_bits(), (-0.0f64).to_bits());
+        assert_eq!(sinpi(-3.0).to_bits(), (-0.0f64).to_bits());
+        assert_eq!(tanpi(1.0).to_bits(), (-0.0f64).to_bits());
+        assert_eq!(tanpi(-1.0).to_bits(), 0.0f64.to_bits());
+        assert_eq!(tanpi(0.5), f64::INFINITY);
+        assert_eq!(tanpi(1.5), f64::NEG_INFINITY);
+        assert_eq!(tanpi(-0.5), f64::NEG_INFINITY);
+        assert_eq!(cospi(4503599627370497.0), -1.0);
+        assert_eq!(cospi(1e300), 1.0);
+        assert_eq!(sinpi(5e-324), 5e-324 * PI);
+        assert!(sinpi(f64::INFINITY).is_nan());
+        assert!(cospi(f64::NAN).is_nan());
+        assert!(tanpi(f64::NEG_INFINITY).is_nan());
+
+        assert_eq!(asinpi(-1.0), -0.5);
+        assert_eq!(acospi(0.0), 0.5);
+        assert_eq!(atanpi(f64::INFINITY), 0.5);
+        assert_eq!(atan2pi(0.0, -1.0), 1.0);
+        assert_eq!(atan2pi(-1.0, 0.0), -0.5);
+    }
+
+    #[test]
+    fn test_degrees_exact() {
+        for n in -12..=12 {
+            let x = 360.0 * n as f64;
+            assert_eq!(sind(x + 30.0), 0.5);
+            assert_eq!(sind(x + 150.0), 0.5);
+            assert_eq!(sind(x - 30.0), -0.5);
+            assert_eq!(sind(x + 90.0), 1.0);
+            assert_eq!(sind(x - 90.0), -1.0);
+            assert_eq!(sind(x + 180.0), 0.0);
+            assert_eq!(cosd(x), 1.0);
+            assert_eq!(cosd(x + 60.0), 0.5);
+            assert_eq!(cosd(x + 120.0), -0.5);
+            assert_eq!(cosd(x + 90.0), 0.0);
+            assert_eq!(cosd(x + 270.0), 0.0);
+            assert_eq!(cosd(x + 180.0), -1.0);
+            assert_eq!(tand(x + 45.0), 1.0);
+            assert_eq!(tand(x + 135.0), -1.0);
+            assert_eq!(tand(x + 90.0), f64::INFINITY);
+            assert_eq!(tand(x + 270.0), f64::NEG_INFINITY);
+            assert_eq!(sind(x + 45.0), core::f64::consts::FRAC_1_SQRT_2);
+        }
+        assert_eq!(sind(-0.0).to_bits(), (-0.0f64).to_bits());
+        assert_eq!(sind(-180.0).to_bits(), (-0.0f64).to_bits());
+        assert_eq!(sind(180.0).to_bits(), 0.0f64.to_bits());
+        assert_eq!(tand(180.0).to_bits(), (-0.0f64).to_bits());
+        assert_eq!(tand(-180.0).to_bits(), 0.0f64.to_bits());
+        for x in [
+            1e20,
+            1e300,
+            f64::MAX,
+            18014398509481986.0,
+            -4503599627370497.0,
+        ] {
+            // % is exact, so both sides see the same remainder
+            assert_eq!(sind(x), sind(x % 360.0));
+            assert_eq!(cosd(x), cosd(x % 360.0));
+        }
+        assert!(sind(f64::INFINITY).is_nan());
+        assert!(tand(f64::NAN).is_nan());
+
+        assert_eq!(asind(0.5), 30.0);
+        assert_eq!(asind(-1.0), -90.0);
+        assert_eq!(acosd(-0.5), 120.0);
+        assert_eq!(acosd(0.0), 90.0);
+        assert_eq!(atand(-1.0), -45.0);
+        assert_eq!(atand(f64::INFINITY), 90.0);
+        assert_eq!(atan2d(-1.0, -1.0), -135.0);
+        assert_eq!(atan2d(0.0, -1.0), 180.0);
+        assert_eq!(atan2d(-2.0, 0.0), -90.0);
+        assert_eq!(atan2d(0.0, 0.0), 0.0);
+        assert!(atan2d(f64::NAN, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_exp2_log2_exact() {
+        for k in -1074..1024 {
+            let x = expi(2.0, k);
+            assert_eq!(exp2(k as f64), x, "k: {k}");
+            assert_eq!(log2(x), k as f64, "k: {k}");
+        }
+        assert_eq!(exp2(1024.0), f64::INFINITY);
+        assert_eq!(exp2(-1075.0), 0.0);
+        assert_eq!(exp2(f64::NEG_INFINITY), 0.0);
+        assert_eq!(log2(0.0), f64::NEG_INFINITY);
+        assert!(log2(-1.0).is_nan());
+    }
+
+    #[test]
+    fn test_exp10_log10_exact() {
+        let mut p = 1.0;
+        for n in 0..=22 {
+            assert_eq!(exp10(n as f64), p, "n: {n}");
+            assert_eq!(log10(p), n as f64, "n: {n}");
+            p *= 10.0;
+        }
+        assert_eq!(exp10(-3.0), 1e-3);
+        assert_eq!(exp10(309.0), f64::INFINITY);
+        assert_eq!(log10(0.0), f64::NEG_INFINITY);
+        assert!(log10(-1.0).is_nan());
+    }
+
+    #[test]
+    fn test_rounding_exact() {
+        const NEG_ZERO: [f64; 7] = [
+            floor(-0.0),
+            ceil(-0.5),
+            trunc(-0.5),
+            round(-0.25),
+            round_ties_even(-0.5),
+            rint(-0.5),
+            nearbyint(-0.0),
+        ];
+        for x in NEG_ZERO {
+            assert_eq!(x.to_bits(), (-0.0f64).to_bits());
+        }
+        const HALFWAY: [f64; 6] = [
+            round(0.5),
+            round(-2.5),
+            round(0.49999999999999994),
+            round_ties_even(0.5),
+            round_ties_even(-2.5),
+            rint(3.5),
+        ];
+        assert_eq!(HALFWAY, [1.0, -3.0, 0.0, 0.0, -2.0, 4.0]);
+        const LARGE: [f64; 4] = [
+            ceil(4503599627370495.5),
+            trunc(-4503599627370495.5),
+            round(4503599627370495.5),
+            rint(4503599627370495.5),
+        ];
+        assert_eq!(
+            LARGE,
+            [
+                4503599627370496.0,
+                -4503599627370495.0,
+                4503599627370496.0,
+                4503599627370496.0
+            ]
+        );
+    }
+
     #[test]
     fn test_ln() {
         // float_eq!(ln(0.01), 0.01_f64.ln());
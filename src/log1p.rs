@@ -65,8 +65,20 @@ const LG5: f64 = 1.818357216161805012e-01; /* 3FC74664 96CB03DE */
 const LG6: f64 = 1.531383769920937332e-01; /* 3FC39A09 D078C69F */
 const LG7: f64 = 1.479819860511658591e-01; /* 3FC2F112 DF3E5244 */
 
-/// The natural logarithm of 1+`x` (f64).
+/// The natural logarithm of 1+`x`
+///
+/// Accurate even when `x` is so small that `1.0 + x` rounds to 1.
+///
+/// ```
+/// # use trig_const::log1p;
+/// const LOG1P_1E_20: f64 = log1p(1e-20);
+/// assert_eq!(LOG1P_1E_20, 1e-20);
+/// ```
 pub const fn log1p(x: f64) -> f64 {
+    nightly_exp!(f64::ln_1p, libm::log1p, log1p_inner, x)
+}
+
+const fn log1p_inner(x: f64) -> f64 {
     let mut ui: u64 = x.to_bits();
 
     let mut f: f64 = 0.;
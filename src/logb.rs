@@ -0,0 +1,25 @@
+/* origin: musl src/math/logb.c */
+
+use crate::ilogb::ilogb_inner;
+
+/// Unbiased binary exponent of `x`, as a float
+///
+/// Like [`ilogb`](crate::ilogb), but `logb(±0)` is `-∞`, `logb(±∞)` is `+∞`
+/// and NaN propagates.
+///
+/// ```
+/// # use trig_const::logb;
+/// const LOGB: f64 = logb(-0.1);
+/// assert_eq!(LOGB, -4.0);
+/// const LOGB_ZERO: f64 = logb(0.0);
+/// assert_eq!(LOGB_ZERO, f64::NEG_INFINITY);
+/// ```
+pub const fn logb(x: f64) -> f64 {
+    if !x.is_finite() {
+        return x * x;
+    }
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    ilogb_inner(x) as f64
+}
@@ -18,4 +18,25 @@ macro_rules! nightly_exp {
             }
         }
     };
+    // Explicit runtime paths, for functions whose `std` and `libm` names differ
+    // (e.g. `f32::sin` and `libm::sinf`)
+    ($std_fn:path, $libm_fn:path, $inner:ident, $($args:ident),*) => {
+        {
+            #[cfg(feature = "nightly")]
+            {
+                #[cfg(feature = "std")]
+                {
+                    std::intrinsics::const_eval_select(($($args,)*), $inner, $std_fn)
+                }
+                #[cfg(not(feature = "std"))]
+                {
+                    core::intrinsics::const_eval_select(($($args,)*), $inner, $libm_fn)
+                }
+            }
+            #[cfg(not(feature = "nightly"))]
+            {
+                $inner($($args),*)
+            }
+        }
+    };
 }
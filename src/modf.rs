@@ -0,0 +1,45 @@
+/* origin: musl src/math/modf.c */
+
+/// Split `x` into fractional and integral parts, both with the sign of `x`
+///
+/// Returns `(fractional, integral)`. Both parts are exact; `modf(±∞)` is
+/// `(±0, ±∞)` and NaN gives NaN for both.
+///
+/// ```
+/// # use trig_const::modf;
+/// const PARTS: (f64, f64) = modf(-3.25);
+/// assert_eq!(PARTS, (-0.25, -3.0));
+/// ```
+pub const fn modf(x: f64) -> (f64, f64) {
+    nightly_exp!(libm::modf, libm::modf, modf_inner, x)
+}
+
+const fn modf_inner(x: f64) -> (f64, f64) {
+    let mut u = x.to_bits();
+    let e = (((u >> 52) & 0x7ff) as i32) - 0x3ff;
+
+    /* no fractional part */
+    if e >= 52 {
+        if e == 0x400 && (u << 12) != 0 {
+            /* nan */
+            return (x, x);
+        }
+        u &= 1 << 63;
+        return (f64::from_bits(u), x);
+    }
+
+    /* no integral part*/
+    if e < 0 {
+        u &= 1 << 63;
+        return (x, f64::from_bits(u));
+    }
+
+    let mask = ((!0) >> 12) >> e;
+    if (u & mask) == 0 {
+        u &= 1 << 63;
+        return (f64::from_bits(u), x);
+    }
+    u &= !mask;
+    let rv2 = f64::from_bits(u);
+    (x - rv2, rv2)
+}
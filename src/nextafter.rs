@@ -0,0 +1,86 @@
+/* origin: musl src/math/nextafter.c */
+
+/// The next representable value after `x` in the direction of `y`
+///
+/// If `x == y`, `y` is returned, so `nextafter(0.0, -0.0)` is `-0.0`. NaN in
+/// either argument propagates.
+///
+/// ```
+/// # use trig_const::nextafter;
+/// const AFTER_1: f64 = nextafter(1.0, 2.0);
+/// assert_eq!(AFTER_1, 1.0 + f64::EPSILON);
+/// const AFTER_0: f64 = nextafter(0.0, -1.0);
+/// assert_eq!(AFTER_0, -5e-324);
+/// ```
+pub const fn nextafter(x: f64, y: f64) -> f64 {
+    nightly_exp!(libm::nextafter, libm::nextafter, nextafter_inner, x, y)
+}
+
+const fn nextafter_inner(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+
+    let mut ux_i = x.to_bits();
+    let uy_i = y.to_bits();
+    if ux_i == uy_i {
+        return y;
+    }
+
+    let ax = ux_i & (!1_u64 / 2);
+    let ay = uy_i & (!1_u64 / 2);
+    if ax == 0 {
+        if ay == 0 {
+            return y;
+        }
+        ux_i = (uy_i & (1_u64 << 63)) | 1;
+    } else if ax > ay || ((ux_i ^ uy_i) & (1_u64 << 63)) != 0 {
+        ux_i -= 1;
+    } else {
+        ux_i += 1;
+    }
+    f64::from_bits(ux_i)
+}
+
+/// The least value greater than `x` (IEEE 754 `nextUp`)
+///
+/// Both zeros step to the smallest positive subnormal, `-5e-324` steps to
+/// `-0.0`, `+∞` is returned unchanged and `-∞` steps to `f64::MIN`. NaN
+/// propagates.
+///
+/// ```
+/// # use trig_const::next_up;
+/// const UP: f64 = next_up(1.0);
+/// assert_eq!(UP, 1.0 + f64::EPSILON);
+/// const UP_NEG_ZERO: f64 = next_up(-0.0);
+/// assert_eq!(UP_NEG_ZERO, 5e-324);
+/// ```
+pub const fn next_up(x: f64) -> f64 {
+    let bits = x.to_bits();
+    if x.is_nan() || bits == f64::INFINITY.to_bits() {
+        return x;
+    }
+
+    let abs = bits & !(1 << 63);
+    let next = if abs == 0 {
+        1
+    } else if bits == abs {
+        bits + 1
+    } else {
+        bits - 1
+    };
+    f64::from_bits(next)
+}
+
+/// The greatest value less than `x` (IEEE 754 `nextDown`)
+///
+/// Equal to `-next_up(-x)`, so both zeros step to `-5e-324`.
+///
+/// ```
+/// # use trig_const::next_down;
+/// const DOWN: f64 = next_down(1.0);
+/// assert_eq!(DOWN, 1.0 - f64::EPSILON / 2.0);
+/// ```
+pub const fn next_down(x: f64) -> f64 {
+    -next_up(-x)
+}
@@ -0,0 +1,370 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/e_powf.c */
+/*
+ * Conversion to float by Ian Lance Taylor, Cygnus Support, ian@cygnus.com.
+ */
+/*
+ * ====================================================
+ * Copyright (C) 1993 by Sun Microsystems, Inc. All rights reserved.
+ *
+ * Developed at SunPro, a Sun Microsystems, Inc. business.
+ * Permission to use, copy, modify, and distribute this
+ * software is freely granted, provided that this notice
+ * is preserved.
+ * ====================================================
+ */
+
+// powf(x,y) return x**y
+//
+// Method: see pow.rs. log2(x) is computed in two pieces t1 + t2, where t1
+// has its low 12 bits cleared, so that y*log2(x) can be formed in float
+// precision without loss.
+//
+// Special cases:
+//      1.  (anything) ** 0  is 1
+//      2.  1 ** (anything)  is 1
+//      3.  (anything except 1) ** NAN is NAN
+//      4.  NAN ** (anything except 0) is NAN
+//      5.  +-(|x| > 1) **  +INF is +INF
+//      6.  +-(|x| > 1) **  -INF is +0
+//      7.  +-(|x| < 1) **  +INF is +0
+//      8.  +-(|x| < 1) **  -INF is +INF
+//      9.  -1          ** +-INF is 1
+//      10. +0 ** (+anything except 0, NAN)               is +0
+//      11. -0 ** (+anything except 0, NAN, odd integer)  is +0
+//      12. +0 ** (-anything except 0, NAN)               is +INF, raise divbyzero
+//      13. -0 ** (-anything except 0, NAN, odd integer)  is +INF, raise divbyzero
+//      14. -0 ** (+odd integer) is -0
+//      15. -0 ** (-odd integer) is -INF, raise divbyzero
+//      16. +INF ** (+anything except 0,NAN) is +INF
+//      17. +INF ** (-anything except 0,NAN) is +0
+//      18. -INF ** (+odd integer) is -INF
+//      19. -INF ** (anything) = -0 ** (-anything), (anything except odd integer)
+//      20. (anything) ** 1 is (anything)
+//      21. (anything) ** -1 is 1/(anything)
+//      22. (-anything) ** (integer) is (-1)**(integer)*(+anything**integer)
+//      23. (-anything except 0 and inf) ** (non-integer) is NAN
+//
+// Constants :
+// The hexadecimal values are the intended ones for the following
+// constants. The decimal values may be used, provided that the
+// compiler will convert from decimal to binary accurately enough
+// to produce the hexadecimal values shown.
+//
+use crate::{scalbnf::scalbnf, sqrtf};
+
+const BP: [f32; 2] = [1.0, 1.5];
+const DP_H: [f32; 2] = [0.0, 5.84960938e-01]; /* 0x3f15c000 */
+const DP_L: [f32; 2] = [0.0, 1.56322085e-06]; /* 0x35d1cfdc */
+const TWO24: f32 = 16777216.0; /* 0x4b800000 */
+const HUGE: f32 = 1.0e30;
+const TINY: f32 = 1.0e-30;
+// poly coefs for (3/2)*(log(x)-2s-2/3*s**3
+const L1: f32 = 6.0000002384e-01; /* 0x3f19999a */
+const L2: f32 = 4.2857143283e-01; /* 0x3edb6db7 */
+const L3: f32 = 3.3333334327e-01; /* 0x3eaaaaab */
+const L4: f32 = 2.7272811532e-01; /* 0x3e8ba305 */
+const L5: f32 = 2.3066075146e-01; /* 0x3e6c3255 */
+const L6: f32 = 2.0697501302e-01; /* 0x3e53f142 */
+const P1: f32 = 1.6666667163e-01; /* 0x3e2aaaab */
+const P2: f32 = -2.7777778450e-03; /* 0xbb360b61 */
+const P3: f32 = 6.6137559770e-05; /* 0x388ab355 */
+const P4: f32 = -1.6533901999e-06; /* 0xb5ddea0e */
+const P5: f32 = 4.1381369442e-08; /* 0x3331bb4c */
+const LG2: f32 = 6.9314718246e-01; /* 0x3f317218 */
+const LG2_H: f32 = 6.93145752e-01; /* 0x3f317200 */
+const LG2_L: f32 = 1.42860654e-06; /* 0x35bfbe8c */
+const OVT: f32 = 4.2995665694e-08; /* -(128-log2(ovfl+.5ulp)) */
+const CP: f32 = 9.6179670095e-01; /* 0x3f76384f =2/(3ln2) */
+const CP_H: f32 = 9.6191406250e-01; /* 0x3f764000 =12b cp */
+const CP_L: f32 = -1.1736857402e-04; /* 0xb8f623c6 =tail of cp_h */
+const IVLN2: f32 = 1.4426950216e+00; /* 0x3fb8aa3b =1/ln2 */
+const IVLN2_H: f32 = 1.4426879883e+00; /* 0x3fb8aa00 =16b 1/ln2*/
+const IVLN2_L: f32 = 7.0526075433e-06; /* 0x36eca570 =1/ln2 tail*/
+
+/// Power function (f32)
+///
+/// ```
+/// # use trig_const::powf;
+/// let _a = powf(10.0, 2.5);
+/// assert_eq!(powf(10.0, 2.0), 100.0);
+/// ```
+pub const fn powf(x: f32, y: f32) -> f32 {
+    nightly_exp!(f32::powf, libm::powf, powf_inner, x, y)
+}
+
+/// Keep only the bits of `x` selected by `mask`
+const fn mask_low(x: f32, mask: u32) -> f32 {
+    f32::from_bits(x.to_bits() & mask)
+}
+
+const fn powf_inner(x: f32, y: f32) -> f32 {
+    let t1: f32;
+    let t2: f32;
+
+    let hx: i32 = x.to_bits() as i32;
+    let hy: i32 = y.to_bits() as i32;
+
+    let mut ix: i32 = hx & 0x7fffffff;
+    let iy: i32 = hy & 0x7fffffff;
+
+    /* x**0 = 1, even if x is NaN */
+    if iy == 0 {
+        return 1.0;
+    }
+
+    /* 1**y = 1, even if y is NaN */
+    if hx == 0x3f800000 {
+        return 1.0;
+    }
+
+    /* NaN if either arg is NaN */
+    if ix > 0x7f800000 || iy > 0x7f800000 {
+        return x + y;
+    }
+
+    /* determine if y is an odd int when x < 0
+     * yisint = 0       ... y is not an integer
+     * yisint = 1       ... y is an odd int
+     * yisint = 2       ... y is an even int
+     */
+    let mut yisint: i32 = 0;
+    if hx < 0 {
+        if iy >= 0x4b800000 {
+            yisint = 2; /* even integer y */
+        } else if iy >= 0x3f800000 {
+            let k = (iy >> 23) - 0x7f; /* exponent */
+            let j = iy >> (23 - k);
+            if (j << (23 - k)) == iy {
+                yisint = 2 - (j & 1);
+            }
+        }
+    }
+
+    /* special value of y */
+    if iy == 0x7f800000 {
+        /* y is +-inf */
+        return if ix == 0x3f800000 {
+            /* (-1)**+-inf is 1 */
+            1.0
+        } else if ix > 0x3f800000 {
+            /* (|x|>1)**+-inf = inf,0 */
+            if hy >= 0 {
+                y
+            } else {
+                0.0
+            }
+        } else {
+            /* (|x|<1)**+-inf = 0,inf */
+            if hy >= 0 {
+                0.0
+            } else {
+                -y
+            }
+        };
+    }
+
+    if iy == 0x3f800000 {
+        /* y is +-1 */
+        return if hy >= 0 { x } else { 1.0 / x };
+    }
+
+    if hy == 0x40000000 {
+        /* y is 2 */
+        return x * x;
+    }
+
+    if hy == 0x3f000000 && hx >= 0 {
+        /* y is 0.5 and x >= +0 */
+        return sqrtf(x);
+    }
+
+    let mut ax: f32 = x.abs();
+    /* special value of x */
+    if ix == 0x7f800000 || ix == 0 || ix == 0x3f800000 {
+        /* x is +-0,+-inf,+-1 */
+        let mut z: f32 = ax;
+
+        if hy < 0 {
+            /* z = (1/|x|) */
+            z = 1.0 / z;
+        }
+
+        if hx < 0 {
+            if ((ix - 0x3f800000) | yisint) == 0 {
+                z = f32::NAN; /* (-1)**non-int is NaN */
+            } else if yisint == 1 {
+                z = -z; /* (x<0)**odd = -(|x|**odd) */
+            }
+        }
+
+        return z;
+    }
+
+    let mut sn: f32 = 1.0; /* sign of result */
+    if hx < 0 {
+        if yisint == 0 {
+            /* (x<0)**(non-int) is NaN */
+            return f32::NAN;
+        }
+
+        if yisint == 1 {
+            /* (x<0)**(odd int) */
+            sn = -1.0;
+        }
+    }
+
+    /* |y| is HUGE */
+    if iy > 0x4d000000 {
+        /* if |y| > 2**27 */
+        /* over/underflow if x is not close to one */
+        if ix < 0x3f7ffff8 {
+            return if hy < 0 {
+                sn * HUGE * HUGE
+            } else {
+                sn * TINY * TINY
+            };
+        }
+        if ix > 0x3f800007 {
+            return if hy > 0 {
+                sn * HUGE * HUGE
+            } else {
+                sn * TINY * TINY
+            };
+        }
+
+        /* now |1-x| is TINY <= 2**-20, suffice to compute
+        log(x) by x-x^2/2+x^3/3-x^4/4 */
+        let t: f32 = ax - 1.0; /* t has 20 trailing zeros */
+        let w: f32 = (t * t) * (0.5 - t * (0.333333333333 - t * 0.25));
+        let u: f32 = IVLN2_H * t; /* IVLN2_H has 16 sig. bits */
+        let v: f32 = t * IVLN2_L - w * IVLN2;
+        t1 = mask_low(u + v, 0xfffff000);
+        t2 = v - (t1 - u);
+    } else {
+        let mut n: i32 = 0;
+
+        if ix < 0x00800000 {
+            /* take care subnormal number */
+            ax *= TWO24;
+            n -= 24;
+            ix = ax.to_bits() as i32;
+        }
+
+        n += (ix >> 23) - 0x7f;
+        let j: i32 = ix & 0x007fffff;
+
+        /* determine interval */
+        let k: usize;
+        ix = j | 0x3f800000; /* normalize ix */
+        if j <= 0x1cc471 {
+            /* |x|<sqrt(3/2) */
+            k = 0;
+        } else if j < 0x5db3d7 {
+            /* |x|<sqrt(3)   */
+            k = 1;
+        } else {
+            k = 0;
+            n += 1;
+            ix -= 0x00800000;
+        }
+        ax = f32::from_bits(ix as u32);
+
+        /* compute s = s_h+s_l = (x-1)/(x+1) or (x-1.5)/(x+1.5) */
+        let u: f32 = ax - BP[k]; /* bp[0]=1.0, bp[1]=1.5 */
+        let v: f32 = 1.0 / (ax + BP[k]);
+        let s: f32 = u * v;
+        let s_h: f32 = mask_low(s, 0xfffff000);
+
+        /* t_h=ax+bp[k] High */
+        let is: u32 = ((ix as u32 >> 1) & 0xfffff000) | 0x20000000;
+        let t_h: f32 = f32::from_bits(is + 0x00400000 + ((k as u32) << 21));
+        let t_l: f32 = ax - (t_h - BP[k]);
+        let s_l: f32 = v * ((u - s_h * t_h) - s_h * t_l);
+
+        /* compute log(ax) */
+        let s2: f32 = s * s;
+        let mut r: f32 = s2 * s2 * (L1 + s2 * (L2 + s2 * (L3 + s2 * (L4 + s2 * (L5 + s2 * L6)))));
+        r += s_l * (s_h + s);
+        let s2: f32 = s_h * s_h;
+        let t_h: f32 = mask_low(3.0 + s2 + r, 0xfffff000);
+        let t_l: f32 = r - ((t_h - 3.0) - s2);
+
+        /* u+v = s*(1+...) */
+        let u: f32 = s_h * t_h;
+        let v: f32 = s_l * t_h + t_l * s;
+
+        /* 2/(3log2)*(s+...) */
+        let p_h: f32 = mask_low(u + v, 0xfffff000);
+        let p_l: f32 = v - (p_h - u);
+        let z_h: f32 = CP_H * p_h; /* cp_h+cp_l = 2/(3*log2) */
+        let z_l: f32 = CP_L * p_h + p_l * CP + DP_L[k];
+
+        /* log2(ax) = (s+..)*2/(3*log2) = n + dp_h + z_h + z_l */
+        let t: f32 = n as f32;
+        t1 = mask_low(((z_h + z_l) + DP_H[k]) + t, 0xfffff000);
+        t2 = z_l - (((t1 - t) - DP_H[k]) - z_h);
+    }
+
+    /* split up y into y1+y2 and compute (y1+y2)*(t1+t2) */
+    let y1: f32 = mask_low(y, 0xfffff000);
+    let p_l: f32 = (y - y1) * t1 + y * t2;
+    let mut p_h: f32 = y1 * t1;
+    let z: f32 = p_l + p_h;
+    let j: i32 = z.to_bits() as i32;
+
+    if j > 0x43000000 {
+        /* if z > 128 */
+        return sn * HUGE * HUGE; /* overflow */
+    } else if j == 0x43000000 {
+        /* if z == 128 */
+        if p_l + OVT > z - p_h {
+            return sn * HUGE * HUGE; /* overflow */
+        }
+    } else if (j & 0x7fffffff) > 0x43160000 {
+        /* z < -150 */
+        return sn * TINY * TINY; /* underflow */
+    } else if j as u32 == 0xc3160000 && p_l <= z - p_h {
+        /* z == -150 */
+        return sn * TINY * TINY; /* underflow */
+    }
+
+    /*
+     * compute 2**(p_h+p_l)
+     */
+    let i: i32 = j & 0x7fffffff;
+    let mut k: i32 = (i >> 23) - 0x7f;
+    let mut n: i32 = 0;
+
+    if i > 0x3f000000 {
+        /* if |z| > 0.5, set n = [z+0.5] */
+        n = j + (0x00800000 >> (k + 1));
+        k = ((n & 0x7fffffff) >> 23) - 0x7f; /* new k for n */
+        let t: f32 = f32::from_bits(n as u32 & !(0x007fffff >> k));
+        n = ((n & 0x007fffff) | 0x00800000) >> (23 - k);
+        if j < 0 {
+            n = -n;
+        }
+        p_h -= t;
+    }
+
+    let t: f32 = mask_low(p_l + p_h, 0xffff8000);
+    let u: f32 = t * LG2_H;
+    let v: f32 = (p_l - (t - p_h)) * LG2 + t * LG2_L;
+    let mut z: f32 = u + v;
+    let w: f32 = v - (z - u);
+    let t: f32 = z * z;
+    let t1: f32 = z - t * (P1 + t * (P2 + t * (P3 + t * (P4 + t * P5))));
+    let r: f32 = (z * t1) / (t1 - 2.0) - (w + z * w);
+    z = 1.0 - (r - z);
+    let mut j: i32 = z.to_bits() as i32;
+    j += n << 23;
+
+    if (j >> 23) <= 0 {
+        /* subnormal output */
+        z = scalbnf(z, n);
+    } else {
+        z = f32::from_bits(j as u32);
+    }
+
+    sn * z
+}
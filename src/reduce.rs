@@ -0,0 +1,162 @@
+//! Argument reduction
+//!
+//! Reduces `x` modulo a multiple of π, as used internally by [`sin`](crate::sin),
+//! [`cos`](crate::cos) and [`tan`](crate::tan). Small and medium arguments use
+//! Cody-Waite reduction with a three-part π/2; larger arguments fall back to
+//! Payne-Hanek reduction, so the remainder is accurate over the whole `f64` range.
+//!
+//! ```
+//! # use trig_const::reduce::{rem_2pi, Reduced};
+//! // Phase of a wavetable oscillator after 1e6 radians
+//! const PHASE: Reduced = rem_2pi(1e6);
+//! assert_eq!(PHASE.quadrant, 159155);
+//! assert!((PHASE.hi - (1e6 - 159155.0 * core::f64::consts::TAU)).abs() < 1e-9);
+//! ```
+
+use core::f64::consts::FRAC_PI_4;
+
+use crate::{dd, rem_pio2};
+
+/// π/2 in three parts
+const PIO2_HI: f64 = 1.57079632679489655800e+00; /* 0x3FF921FB, 0x54442D18 */
+const PIO2_LO: f64 = 6.12323399573676603587e-17; /* 0x3C91A626, 0x33145C07 */
+const PIO2_LO2: f64 = -1.49738490485916983e-33; /* 0xB91F1976, 0xB7ED8FBC */
+/// 53 bits of 2/pi
+const INV_PIO2: f64 = 6.36619772367581382433e-01; /* 0x3FE45F30, 0x6DC9C883 */
+/// 1.5 * 2^52, for rounding to the nearest integer
+const TO_INT: f64 = 6755399441055744.0;
+
+/// The result of an argument reduction
+///
+/// `x = quadrant * period + (hi + lo)`, where `hi + lo` is a double-double remainder
+/// with `|hi + lo| <= period / 2`.
+///
+/// For `|x| >= 2^20 * π/2` only the low bits of `quadrant` are computed: it is exact
+/// modulo `8 * π/2 / period`, which is all that is needed to pick a quadrant or octant.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Reduced {
+    /// Number of whole periods removed from `x`
+    pub quadrant: i32,
+    /// Leading part of the remainder
+    pub hi: f64,
+    /// Trailing part of the remainder, below one ulp of `hi`
+    pub lo: f64,
+}
+
+/// Reduce `x` modulo π/2
+///
+/// The remainder lies in `[-π/4, π/4]`.
+pub const fn rem_pio2(x: f64) -> Reduced {
+    rem_pi_over_2k(x, 1)
+}
+
+/// Reduce `x` modulo π
+///
+/// The remainder lies in `[-π/2, π/2]`.
+pub const fn rem_pi(x: f64) -> Reduced {
+    rem_pi_over_2k(x, 0)
+}
+
+/// Reduce `x` modulo 2π
+///
+/// The remainder lies in `[-π, π]`.
+pub const fn rem_2pi(x: f64) -> Reduced {
+    match pio2(x) {
+        Some((n, y0, y1)) => coarsen(n, y0, y1, 2),
+        None => nan(),
+    }
+}
+
+/// Reduce `x` modulo π/2^k
+///
+/// The remainder lies in `[-π/2^(k+1), π/2^(k+1)]`. `quadrant` wraps on overflow.
+///
+/// # Panics
+///
+/// Panics if `k > 52`.
+pub const fn rem_pi_over_2k(x: f64, k: u32) -> Reduced {
+    assert!(k <= 52, "k must be at most 52");
+    let (n, y0, y1) = match pio2(x) {
+        Some(r) => r,
+        None => return nan(),
+    };
+    if k == 0 {
+        return coarsen(n, y0, y1, 1);
+    }
+    if k == 1 {
+        return Reduced {
+            quadrant: n,
+            hi: y0,
+            lo: y1,
+        };
+    }
+
+    /* the period, (pi/2) * 2^(1-k), is exact in three parts */
+    let scale = f64::from_bits(((0x3ff + 1 - k as u64) & 0x7ff) << 52);
+    let (c, c_lo, c_lo2) = (PIO2_HI * scale, PIO2_LO * scale, PIO2_LO2 * scale);
+
+    /* m = rint(y / c), at most 2^(k-2) in magnitude */
+    let mut m = (y0 * (INV_PIO2 / scale) + TO_INT) - TO_INT;
+    let (mut hi, mut lo) = sub_multiple(y0, y1, m, c, c_lo, c_lo2);
+    /* for large k, y / c has too few fraction bits left to round it reliably */
+    if hi > 0.5 * c {
+        m += 1.0;
+        (hi, lo) = sub_multiple(y0, y1, m, c, c_lo, c_lo2);
+    } else if hi < -0.5 * c {
+        m -= 1.0;
+        (hi, lo) = sub_multiple(y0, y1, m, c, c_lo, c_lo2);
+    }
+
+    Reduced {
+        /* shift in 64 bits: an i32 shift would mask k - 1 to 5 bits */
+        quadrant: (n as i64).wrapping_shl(k - 1).wrapping_add(m as i64) as i32,
+        hi,
+        lo,
+    }
+}
+
+/// `y0 + y1 - m * (c + c_lo + c_lo2)`, in double-double
+const fn sub_multiple(y0: f64, y1: f64, m: f64, c: f64, c_lo: f64, c_lo2: f64) -> (f64, f64) {
+    let (p, p_err) = dd::two_prod(m, c);
+    let (s, s_err) = dd::two_sum(y0, -p);
+    let lo = s_err - p_err + y1 - m * c_lo - m * c_lo2;
+    dd::fast_two_sum(s, lo)
+}
+
+/// Reduction modulo π/2, or `None` for inf and NaN
+const fn pio2(x: f64) -> Option<(i32, f64, f64)> {
+    if !x.is_finite() {
+        return None;
+    }
+    /* |x| <= pi/4, which rem_pio2 leaves to its callers */
+    if x.abs() <= FRAC_PI_4 {
+        return Some((0, x, 0.0));
+    }
+    Some(rem_pio2::rem_pio2(x))
+}
+
+/// Turn a reduction modulo π/2 into one modulo 2^bits * π/2
+const fn coarsen(n: i32, y0: f64, y1: f64, bits: u32) -> Reduced {
+    let mask = (1 << bits) - 1;
+    let half = 1 << (bits - 1);
+    let mut j = n & mask;
+    let mut quadrant = n >> bits;
+    if j > half || (j == half && y0 > 0.0) {
+        j -= 1 << bits;
+        quadrant += 1;
+    }
+
+    /* y + j * pi/2, where j * PIO2_HI is exact for |j| <= 2 */
+    let j = j as f64;
+    let (s, s_err) = dd::two_sum(y0, j * PIO2_HI);
+    let (hi, lo) = dd::fast_two_sum(s, s_err + y1 + j * PIO2_LO);
+    Reduced { quadrant, hi, lo }
+}
+
+const fn nan() -> Reduced {
+    Reduced {
+        quadrant: 0,
+        hi: f64::NAN,
+        lo: f64::NAN,
+    }
+}
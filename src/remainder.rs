@@ -0,0 +1,24 @@
+/* origin: musl src/math/remainder.c */
+
+use crate::remquo::remquo_inner;
+
+/// IEEE remainder of `x / y`
+///
+/// The result is exactly `x - n * y`, where `n` is `x / y` rounded to the
+/// nearest integer with ties to even, so it always lies in `[-|y| / 2, |y| / 2]`.
+/// A zero result has the sign of `x`.
+///
+/// ```
+/// # use trig_const::remainder;
+/// const REM: f64 = remainder(7.5, 2.0);
+/// assert_eq!(REM, -0.5);
+/// const REM_TIE: f64 = remainder(5.0, 2.0);
+/// assert_eq!(REM_TIE, 1.0);
+/// ```
+pub const fn remainder(x: f64, y: f64) -> f64 {
+    nightly_exp!(libm::remainder, libm::remainder, remainder_inner, x, y)
+}
+
+const fn remainder_inner(x: f64, y: f64) -> f64 {
+    remquo_inner(x, y).0
+}
@@ -0,0 +1,64 @@
+/* SPDX-License-Identifier: MIT
+ * origin: musl src/math/rint.c */
+
+//! Rounding to the nearest integer, with ties to even.
+//!
+//! `rint` and `nearbyint` round in the current rounding mode. Const evaluation
+//! always rounds to nearest, so all three functions here agree.
+
+use crate::floor::{exp_unbiased, SIG_BITS};
+
+/// Rounds to the nearest integer, with ties to even
+///
+/// ```
+/// # use trig_const::round_ties_even;
+/// const ROUND: f64 = round_ties_even(2.5);
+/// assert_eq!(ROUND, 2.0);
+/// ```
+pub const fn round_ties_even(x: f64) -> f64 {
+    nightly_exp!(f64::round_ties_even, libm::rint, rint_inner, x)
+}
+
+/// Rounds to an integer in the current rounding mode, which is always
+/// to-nearest with ties to even in const evaluation
+///
+/// ```
+/// # use trig_const::rint;
+/// const RINT: f64 = rint(-3.5);
+/// assert_eq!(RINT, -4.0);
+/// ```
+pub const fn rint(x: f64) -> f64 {
+    nightly_exp!(f64::round_ties_even, libm::rint, rint_inner, x)
+}
+
+/// Rounds to an integer in the current rounding mode without raising the
+/// inexact exception; identical to [`rint`] here
+///
+/// ```
+/// # use trig_const::nearbyint;
+/// const NEARBYINT: f64 = nearbyint(0.5);
+/// assert_eq!(NEARBYINT, 0.0);
+/// ```
+pub const fn nearbyint(x: f64) -> f64 {
+    nightly_exp!(f64::round_ties_even, libm::rint, rint_inner, x)
+}
+
+const fn rint_inner(x: f64) -> f64 {
+    /* 2^52: adding and subtracting it rounds away the fractional bits */
+    const TO_INT: f64 = 1.0 / f64::EPSILON;
+
+    if exp_unbiased(x) >= SIG_BITS as i32 {
+        /* already an integer, or inf or nan */
+        return x;
+    }
+    let y = if x.is_sign_negative() {
+        x - TO_INT + TO_INT
+    } else {
+        x + TO_INT - TO_INT
+    };
+    if y == 0.0 {
+        /* keep the sign of x */
+        return if x.is_sign_negative() { -0.0 } else { 0.0 };
+    }
+    y
+}
@@ -0,0 +1,22 @@
+/* SPDX-License-Identifier: MIT OR Apache-2.0
+ * origin: Rust libm src/math/generic/round.rs */
+
+use crate::trunc::trunc_inner;
+
+/// Rounds to the nearest integer, with ties away from zero
+///
+/// ```
+/// # use trig_const::round;
+/// const ROUND: f64 = round(-2.5);
+/// assert_eq!(ROUND, -3.0);
+/// ```
+pub const fn round(x: f64) -> f64 {
+    nightly_exp!(round, round_inner, x)
+}
+
+const fn round_inner(x: f64) -> f64 {
+    /* the largest value below 0.5, so that x + HALF never rounds up across an integer */
+    const HALF: f64 = 0.5 - 0.25 * f64::EPSILON;
+    let half = if x.is_sign_negative() { -HALF } else { HALF };
+    trunc_inner(x + half)
+}
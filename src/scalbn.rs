@@ -23,7 +23,19 @@ const EXP_MIN: i32 = -(EXP_MAX - 1);
 /// >
 /// > If the calculation does not overflow or underflow, the returned value is exact and
 /// > independent of the current rounding direction mode.
-pub(crate) const fn scalbn(mut x: f64, mut n: i32) -> f64 {
+///
+/// ```
+/// # use trig_const::scalbn;
+/// const SCALED: f64 = scalbn(1.5, 4);
+/// assert_eq!(SCALED, 24.0);
+/// const SUBNORMAL: f64 = scalbn(1.0, -1074);
+/// assert_eq!(SUBNORMAL, 5e-324);
+/// ```
+pub const fn scalbn(x: f64, n: i32) -> f64 {
+    nightly_exp!(libm::scalbn, libm::scalbn, scalbn_inner, x, n)
+}
+
+const fn scalbn_inner(mut x: f64, mut n: i32) -> f64 {
     let zero = 0;
 
     // Bits including the implicit bit
@@ -0,0 +1,66 @@
+//! Cardinal sine
+
+use core::f64::consts::PI;
+
+use crate::{dd, sin, sinpi};
+
+/// π - PI, the tail of π as a double-double
+const PI_TAIL: f64 = 1.22464679914735317723e-16; /* 0x3CA1A626, 0x33145C07 */
+/// Below this, `sin(t)/t = 1 - t^2/6` to within 2^-80
+const TINY: f64 = 9.5367431640625e-7; /* 0x1p-20 */
+/// 2^52, above which every `f64` is an integer
+const TWO52: f64 = 4503599627370496.0;
+
+/// Normalized sinc function, `sin(πx) / (πx)`
+///
+/// `sinc(0)` is exactly 1 and `sinc(n)` is exactly 0 for every nonzero integer
+/// `n`. `sinc(±∞)` is 0, the limit. `πx` is formed in double-double, so the
+/// result stays accurate to a few ulp for large `x`.
+///
+/// ```
+/// # use trig_const::sinc;
+/// const SINC_0: f64 = sinc(0.0);
+/// assert_eq!(SINC_0, 1.0);
+/// const SINC_3: f64 = sinc(3.0);
+/// assert_eq!(SINC_3, 0.0);
+/// const SINC_HALF: f64 = sinc(0.5);
+/// assert_eq!(SINC_HALF, 2.0 / core::f64::consts::PI);
+/// ```
+pub const fn sinc(x: f64) -> f64 {
+    /* sinc is even */
+    let ax = x.abs();
+    if ax < TINY {
+        let t = PI * ax;
+        return 1.0 - t * t / 6.0;
+    }
+    if ax >= TWO52 {
+        return 0.0;
+    }
+    let (hi, lo) = dd::two_prod(ax, PI);
+    let lo = lo + ax * PI_TAIL;
+    /* sinpi(x) / (hi + lo), to first order in lo */
+    let q = sinpi(ax) / hi;
+    q - q * (lo / hi)
+}
+
+/// Unnormalized sinc function, `sin(x) / x`
+///
+/// `sinc_unnormalized(0)` is exactly 1, and `sinc_unnormalized(±∞)` is 0.
+///
+/// ```
+/// # use trig_const::sinc_unnormalized;
+/// const SINC_0: f64 = sinc_unnormalized(0.0);
+/// assert_eq!(SINC_0, 1.0);
+/// const SINC_PI_2: f64 = sinc_unnormalized(core::f64::consts::FRAC_PI_2);
+/// assert_eq!(SINC_PI_2, 2.0 / core::f64::consts::PI);
+/// ```
+pub const fn sinc_unnormalized(x: f64) -> f64 {
+    let ax = x.abs();
+    if ax < TINY {
+        return 1.0 - ax * ax / 6.0;
+    }
+    if ax == f64::INFINITY {
+        return 0.0;
+    }
+    sin(ax) / ax
+}
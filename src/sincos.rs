@@ -0,0 +1,70 @@
+// origin: FreeBSD /usr/src/lib/msun/src/s_sincos.c
+//
+// ====================================================
+// Copyright (C) 1993 by Sun Microsystems, Inc. All rights reserved.
+//
+// Developed at SunPro, a Sun Microsystems, Inc. business.
+// Permission to use, copy, modify, and distribute this
+// software is freely granted, provided that this notice
+// is preserved.
+// ====================================================
+//
+// s_sin.c and s_cos.c merged by Steven G. Kargl.  Descriptions of the
+// algorithms are contained in the original files.
+
+use crate::{k_cos::k_cos, k_sin::k_sin, rem_pio2::rem_pio2};
+
+/// Simultaneous sine and cosine
+///
+/// Returns `(sin(x), cos(x))`, sharing one argument reduction. Both results
+/// are bit-identical to [`sin`](crate::sin) and [`cos`](crate::cos).
+///
+/// ```
+/// # use trig_const::sincos;
+/// # use core::f64::consts::PI;
+/// # fn float_eq(lhs: f64, rhs: f64) { assert!((lhs - rhs).abs() < 0.0001, "lhs: {}, rhs: {}", lhs, rhs); }
+/// const SINCOS_PI: (f64, f64) = sincos(PI);
+/// float_eq(SINCOS_PI.0, 0.0);
+/// float_eq(SINCOS_PI.1, -1.0);
+/// ```
+pub const fn sincos(x: f64) -> (f64, f64) {
+    nightly_exp!(f64::sin_cos, libm::sincos, sincos_inner, x)
+}
+
+const fn sincos_inner(x: f64) -> (f64, f64) {
+    let ix = (f64::to_bits(x) >> 32) as u32 & 0x7fffffff;
+
+    /* |x| ~< pi/4 */
+    if ix <= 0x3fe921fb {
+        /* the thresholds for tiny x differ between sin and cos */
+        let s = if ix < 0x3e500000 {
+            /* |x| < 2**-26 */
+            x
+        } else {
+            k_sin(x, 0.0, 0)
+        };
+        let c = if ix < 0x3e46a09e && x as i32 == 0 {
+            /* if x < 2**-27 * sqrt(2) */
+            1.0
+        } else {
+            k_cos(x, 0.0)
+        };
+        return (s, c);
+    }
+
+    /* sincos(Inf or NaN) is NaN */
+    if ix >= 0x7ff00000 {
+        return (f64::NAN, f64::NAN);
+    }
+
+    /* argument reduction needed */
+    let (n, y0, y1) = rem_pio2(x);
+    let s = k_sin(y0, y1, 1);
+    let c = k_cos(y0, y1);
+    match n & 3 {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    }
+}
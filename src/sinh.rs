@@ -0,0 +1,50 @@
+/* origin: musl src/math/sinh.c */
+
+use crate::{expm1::expm1, expo2::expo2};
+
+// sinh(x) = (exp(x) - 1/exp(x))/2
+//         = (exp(x)-1 + (exp(x)-1)/exp(x))/2
+//         = x + x^3/6 + o(x^5)
+//
+// Computing through expm1 keeps full relative precision near zero, where
+// (exp(x) - exp(-x))/2 cancels.
+
+/// Hyperbolic Sine
+///
+/// ```
+/// # use trig_const::sinh;
+/// const SINH_0: f64 = sinh(0.0);
+/// assert_eq!(SINH_0, 0.0);
+/// ```
+pub const fn sinh(x: f64) -> f64 {
+    nightly_exp!(sinh, sinh_inner, x)
+}
+
+const fn sinh_inner(x: f64) -> f64 {
+    let mut h = 0.5;
+    if x.is_sign_negative() {
+        h = -h;
+    }
+
+    /* |x| */
+    let absx = x.abs();
+    let w = (absx.to_bits() >> 32) as u32;
+
+    /* |x| < log(DBL_MAX) */
+    if w < 0x40862e42 {
+        let t = expm1(absx);
+        if w < 0x3ff00000 {
+            if w < 0x3ff00000 - (26 << 20) {
+                /* note: inexact and underflow are raised by expm1 */
+                /* note: this branch avoids spurious underflow */
+                return x;
+            }
+            return h * (2.0 * t - t * t / (t + 1.0));
+        }
+        /* note: |x|>log(0x1p26)+eps could be just h*exp(x) */
+        return h * (t + t / (t + 1.0));
+    }
+
+    /* |x| > log(DBL_MAX) or nan */
+    2.0 * h * expo2(absx)
+}
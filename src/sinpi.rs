@@ -0,0 +1,139 @@
+// origin: FreeBSD /usr/src/lib/msun/src/s_sinpi.c
+//
+// ====================================================
+// Copyright (c) 2017 Steven G. Kargl
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions
+// are met:
+// 1. Redistributions of source code must retain the above copyright
+//    notice unmodified, this list of conditions, and the following
+//    disclaimer.
+// 2. Redistributions in binary form must reproduce the above copyright
+//    notice, this list of conditions and the following disclaimer in the
+//    documentation and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+// IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+// OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+// IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+// INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+// NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+// DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+// THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+// (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+// THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+// ====================================================
+//
+// The principal argument of sinpi(x) is x - n, with 0 <= x - n < 1 and n
+// the integer part of |x|.  Then
+//
+//   sinpi(x) = sinpi(x - n) * (-1)^n
+//
+// and sinpi(x - n) is evaluated by one of the kernels on [0, 0.25]:
+//
+//   [0, 0.25)    sinpi(r)       = k_sinpi(r)
+//   [0.25, 0.5)  sinpi(r)       = k_cospi(0.5 - r)
+//   [0.5, 0.75)  sinpi(r)       = k_cospi(r - 0.5)
+//   [0.75, 1)    sinpi(r)       = k_sinpi(1 - r)
+//
+// Each subtraction above is exact, so integers and half-integers give
+// exactly +-0 and +-1.
+//
+// Special cases:
+//   sinpi(+-0) = +-0
+//   sinpi(+-n) = +-0, for positive integers n.
+//   sinpi(+-inf) = nan.  Raises the "invalid" floating-point exception.
+//   sinpi(nan) = nan.  Raises the "invalid" floating-point exception.
+
+use crate::{
+    floor,
+    k_cospi::k_cospi,
+    k_sinpi::{k_sinpi, PI_HI, PI_LO},
+};
+
+/// Sine of π times x, sin(πx)
+///
+/// ```
+/// # use trig_const::sinpi;
+/// const SINPI_1: f64 = sinpi(1.0);
+/// assert_eq!(SINPI_1.to_bits(), 0.0f64.to_bits());
+/// const SINPI_3_2: f64 = sinpi(1.5);
+/// assert_eq!(SINPI_3_2, -1.0);
+/// ```
+pub const fn sinpi(x: f64) -> f64 {
+    let hx = (x.to_bits() >> 32) as u32;
+    let ix = hx & 0x7fffffff;
+    let mut ax = x.abs();
+
+    let s = if ix < 0x3ff00000 {
+        /* |x| < 1 */
+        if ix < 0x3fd00000 {
+            /* |x| < 0.25 */
+            if ix < 0x3de00000 {
+                /* |x| < 0x1p-33 */
+                if x == 0.0 {
+                    return x;
+                }
+                // To avoid issues with subnormal values,
+                // scale the computation and rescale on
+                // return.
+                let hi = f64::from_bits((hx as u64) << 32) * f64::from_bits(0x4340000000000000);
+                let lo = x * f64::from_bits(0x4340000000000000) - hi;
+                let s = (PI_LO + PI_HI) * lo + PI_LO * hi + PI_HI * hi;
+                return s * f64::from_bits(0x3ca0000000000000);
+            }
+            k_sinpi(ax)
+        } else if ix < 0x3fe00000 {
+            /* |x| < 0.5 */
+            k_cospi(0.5 - ax)
+        } else if ix < 0x3fe80000 {
+            /* |x| < 0.75 */
+            k_cospi(ax - 0.5)
+        } else {
+            k_sinpi(1.0 - ax)
+        }
+    } else if ix < 0x43300000 {
+        /* 1 <= |x| < 0x1p52 */
+        let n = floor(ax); /* Integer part of ax. */
+        ax -= n;
+        let ix = (ax.to_bits() >> 32) as u32;
+
+        let s = if ax == 0.0 {
+            0.0
+        } else if ix < 0x3fe00000 {
+            /* |x| < 0.5 */
+            if ix < 0x3fd00000 {
+                /* |x| < 0.25 */
+                k_sinpi(ax)
+            } else {
+                k_cospi(0.5 - ax)
+            }
+        } else if ix < 0x3fe80000 {
+            /* |x| < 0.75 */
+            k_cospi(ax - 0.5)
+        } else {
+            k_sinpi(1.0 - ax)
+        };
+
+        /* An integer gives +0, signed below like x, so skip the (-1)^n. */
+        if ax != 0.0 && n as u64 & 1 == 1 {
+            -s
+        } else {
+            s
+        }
+    } else if ix >= 0x7ff00000 {
+        /* x = +-inf or nan. */
+        return f64::NAN;
+    } else {
+        /* |x| >= 0x1p52 is always an integer, so return +-0. */
+        0.0
+    };
+
+    if hx & 0x80000000 != 0 {
+        -s
+    } else {
+        s
+    }
+}
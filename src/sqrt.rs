@@ -0,0 +1,97 @@
+/* sqrt(x)
+ * Return correctly rounded sqrt.
+ *
+ * Method:
+ *   Bit by bit method using integer arithmetic, as in
+ *   FreeBSD /usr/src/lib/msun/src/e_sqrt.c, but carried out in a single
+ *   128-bit integer instead of two 32-bit words.
+ *   1. Normalization
+ *      Scale x to m * 2^(2k), where the integer m has 54 or 55 significant
+ *      bits (subnormals are normalized first), so that
+ *              sqrt(x) = sqrt(m) * 2^k
+ *   2. Bit by bit computation
+ *      Shift m left by 54 bits and compute q = floor(sqrt(m)) one bit at a
+ *      time, from the most significant bit down. q has exactly 54 bits:
+ *      the 53 bits of the result and one rounding bit.
+ *   3. Final rounding
+ *      The exact square root of a double can never lie halfway between two
+ *      doubles, so round to nearest reduces to adding the rounding bit.
+ *
+ * Special cases:
+ *      sqrt(+-0) = +-0         ... exact
+ *      sqrt(inf) = inf
+ *      sqrt(-ve) = NaN         ... with invalid signal
+ *      sqrt(NaN) = NaN         ... with invalid signal for signaling NaN
+ */
+
+const SIG_BITS: u32 = 52;
+const EXP_BIAS: i32 = 1023;
+const SIG_MASK: u64 = (1 << SIG_BITS) - 1;
+
+/// Square root, correctly rounded
+///
+/// The result is the same as IEEE 754 `sqrt` (and [`f64::sqrt`]) for every input.
+///
+/// ```
+/// # use trig_const::sqrt;
+/// const SQRT_2: f64 = sqrt(2.0);
+/// assert_eq!(SQRT_2, core::f64::consts::SQRT_2);
+/// ```
+pub const fn sqrt(x: f64) -> f64 {
+    nightly_exp!(sqrt, sqrt_inner, x)
+}
+
+const fn sqrt_inner(x: f64) -> f64 {
+    let bits = x.to_bits();
+
+    /* take care of zero, negatives, Inf and NaN */
+    if bits << 1 == 0 {
+        return x; /* sqrt(+-0) = +-0 */
+    }
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN; /* sqrt(NaN) = NaN, sqrt(-ve) = NaN */
+    }
+    if x.is_infinite() {
+        return x; /* sqrt(+inf) = +inf */
+    }
+
+    /* normalize x */
+    let mut e = (bits >> SIG_BITS) as i32;
+    let mut m = bits & SIG_MASK;
+    if e == 0 {
+        /* subnormal x */
+        let shift = m.leading_zeros() as i32 - (63 - SIG_BITS as i32);
+        m <<= shift;
+        e = 1 - shift;
+    } else {
+        m |= 1 << SIG_BITS;
+    }
+    e -= EXP_BIAS; /* unbias exponent */
+    if e & 1 != 0 {
+        /* odd exponent, double m to make it even */
+        m <<= 1;
+        e -= 1;
+    }
+
+    /* generate sqrt(m << 54) bit by bit */
+    let mut rem = (m as u128) << 54;
+    let mut q: u128 = 0;
+    let mut bit: u128 = 1 << 106; /* largest power of 4 <= rem */
+    while bit != 0 {
+        if rem >= q + bit {
+            rem -= q + bit;
+            q = (q >> 1) + bit;
+        } else {
+            q >>= 1;
+        }
+        bit >>= 2;
+    }
+
+    /* q has 54 bits; drop the rounding bit and round to nearest */
+    let q = ((q >> 1) + (q & 1)) as u64;
+
+    /* q includes the implicit bit, so a carry out of the significand
+     * correctly bumps the exponent */
+    let exp = (e / 2 + EXP_BIAS - 1) as u64;
+    f64::from_bits((exp << SIG_BITS) + q)
+}
@@ -0,0 +1,131 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/e_sqrtf.c */
+/*
+ * Conversion to float by Ian Lance Taylor, Cygnus Support, ian@cygnus.com.
+ */
+/*
+ * ====================================================
+ * Copyright (C) 1993 by Sun Microsystems, Inc. All rights reserved.
+ *
+ * Developed at SunPro, a Sun Microsystems, Inc. business.
+ * Permission to use, copy, modify, and distribute this
+ * software is freely granted, provided that this notice
+ * is preserved.
+ * ====================================================
+ */
+/* sqrtf(x)
+ * Return correctly rounded sqrt.
+ *
+ * Method:
+ *   Bit by bit method using integer arithmetic.
+ *   1. Normalization
+ *      Scale x to y in [1,4) with even powers of 2:
+ *      find an integer k such that  1 <= (y=x*2^(2k)) < 4, then
+ *              sqrt(x) = 2^k * sqrt(y)
+ *   2. Bit by bit computation
+ *      Let q  = sqrt(y) truncated to i bit after binary point (q = 1),
+ *           i                                                   0
+ *                                     i+1         2
+ *          s  = 2*q , and      y  =  2   * ( y - q  ).
+ *           i      i            i                 i
+ *
+ *      To compute q    from q , one checks whether
+ *                  i+1       i
+ *
+ *                            -(i+1) 2
+ *                      (q + 2      ) <= y.
+ *                        i
+ *      If (2) is false, then q   = q ; otherwise q   = q  + 2^-(i+1).
+ *                             i+1   i             i+1   i
+ *   3. Final rounding
+ *      After generating the 24 bits result plus a remainder, the
+ *      remainder decides whether the result is rounded up (round to
+ *      nearest, ties to even).
+ *
+ * Special cases:
+ *      sqrt(+-0) = +-0         ... exact
+ *      sqrt(inf) = inf
+ *      sqrt(-ve) = NaN         ... with invalid signal
+ *      sqrt(NaN) = NaN         ... with invalid signal for signaling NaN
+ */
+
+/// Square root (f32), correctly rounded
+///
+/// ```
+/// # use trig_const::sqrtf;
+/// const SQRT_2: f32 = sqrtf(2.0);
+/// assert_eq!(SQRT_2, core::f32::consts::SQRT_2);
+/// ```
+pub const fn sqrtf(x: f32) -> f32 {
+    nightly_exp!(f32::sqrt, libm::sqrtf, sqrtf_inner, x)
+}
+
+const fn sqrtf_inner(x: f32) -> f32 {
+    let sign: i32 = 0x80000000u32 as i32;
+    let mut ix: i32 = x.to_bits() as i32;
+
+    /* take care of Inf and NaN */
+    if (ix as u32 & 0x7f800000) == 0x7f800000 {
+        if x.is_nan() || ix < 0 {
+            /* sqrt(NaN)=NaN, sqrt(-inf)=NaN */
+            return f32::NAN;
+        }
+        /* sqrt(+inf)=+inf */
+        return x;
+    }
+
+    /* take care of zero */
+    if ix <= 0 {
+        if (ix & !sign) == 0 {
+            return x; /* sqrt(+-0) = +-0 */
+        }
+        if ix < 0 {
+            return f32::NAN; /* sqrt(-ve) = NaN */
+        }
+    }
+
+    /* normalize x */
+    let mut m = ix >> 23;
+    if m == 0 {
+        /* subnormal x */
+        let mut i = 0;
+        while ix & 0x00800000 == 0 {
+            ix <<= 1;
+            i += 1;
+        }
+        m -= i - 1;
+    }
+    m -= 127; /* unbias exponent */
+    ix = (ix & 0x007fffff) | 0x00800000;
+    if m & 1 == 1 {
+        /* odd m, double x to make it even */
+        ix += ix;
+    }
+    m >>= 1; /* m = [m/2] */
+
+    /* generate sqrt(x) bit by bit */
+    ix += ix;
+    let mut q: i32 = 0;
+    let mut s: i32 = 0;
+    let mut r: u32 = 0x01000000; /* r = moving bit from right to left */
+
+    while r != 0 {
+        let t = s + r as i32;
+        if t <= ix {
+            s = t + r as i32;
+            ix -= t;
+            q += r as i32;
+        }
+        ix += ix;
+        r >>= 1;
+    }
+
+    /* use the remainder to find out rounding direction (round to nearest:
+     * the exact root is never halfway between two floats) */
+    if ix != 0 {
+        q += q & 1;
+    }
+
+    ix = (q >> 1) + 0x3f000000;
+    ix += m << 23;
+    f32::from_bits(ix as u32)
+}
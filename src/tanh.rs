@@ -0,0 +1,54 @@
+/* origin: musl src/math/tanh.c */
+
+use crate::expm1::expm1;
+
+// tanh(x) = (exp(x) - exp(-x))/(exp(x) + exp(-x))
+//         = (exp(2*x) - 1)/(exp(2*x) - 1 + 2)
+//         = (1 - exp(-2*x))/(exp(-2*x) - 1 + 2)
+
+/// Hyperbolic Tangent
+///
+/// ```
+/// # use trig_const::tanh;
+/// const TANH_0: f64 = tanh(0.0);
+/// assert_eq!(TANH_0, 0.0);
+/// ```
+pub const fn tanh(x: f64) -> f64 {
+    nightly_exp!(tanh, tanh_inner, x)
+}
+
+const fn tanh_inner(x: f64) -> f64 {
+    /* x = |x| */
+    let sign = x.is_sign_negative();
+    let x = x.abs();
+    let w = (x.to_bits() >> 32) as u32;
+
+    let t = if w > 0x3fe193ea {
+        /* |x| > log(3)/2 ~= 0.5493 or nan */
+        if w > 0x40340000 {
+            /* |x| > 20 or nan */
+            /* note: this branch avoids raising overflow */
+            1.0 - 0.0 / x
+        } else {
+            let t = expm1(2.0 * x);
+            1.0 - 2.0 / (t + 2.0)
+        }
+    } else if w > 0x3fd058ae {
+        /* |x| > log(5/3)/2 ~= 0.2554 */
+        let t = expm1(2.0 * x);
+        t / (t + 2.0)
+    } else if w >= 0x00100000 {
+        /* |x| >= 0x1p-1022, up to 2ulp error in [0.1,0.2554] */
+        let t = expm1(-2.0 * x);
+        -t / (t + 2.0)
+    } else {
+        /* |x| is subnormal */
+        x
+    };
+
+    if sign {
+        -t
+    } else {
+        t
+    }
+}
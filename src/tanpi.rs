@@ -0,0 +1,149 @@
+// origin: FreeBSD /usr/src/lib/msun/src/s_tanpi.c
+//
+// ====================================================
+// Copyright (c) 2017 Steven G. Kargl
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions
+// are met:
+// 1. Redistributions of source code must retain the above copyright
+//    notice unmodified, this list of conditions, and the following
+//    disclaimer.
+// 2. Redistributions in binary form must reproduce the above copyright
+//    notice, this list of conditions and the following disclaimer in the
+//    documentation and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+// IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+// OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+// IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+// INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+// NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+// DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+// THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+// (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+// THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+// ====================================================
+//
+// The principal argument of tanpi(x) is x - n, with 0 <= x - n < 1 and n
+// the integer part of |x|.  As tanpi(x) has period 1,
+//
+//   tanpi(x) = tanpi(x - n)
+//
+// and tanpi(x - n) is evaluated by the kernel on [0, 0.5], with
+//
+//   [0.5, 1)     tanpi(r)       = -k_tanpi(1 - r)
+//
+// Special cases:
+//   tanpi(+-0) = +-0
+//   tanpi(n) = +0 for positive even and negative odd integer n.
+//   tanpi(n) = -0 for positive odd and negative even integer n.
+//   tanpi(+-n+1/4) = +-1, for positive integers n.
+//   tanpi(n+1/2) = +inf and raises the FE_DIVBYZERO exception for
+//     even integers n.
+//   tanpi(n+1/2) = -inf and raises the FE_DIVBYZERO exception for
+//     odd integers n.
+//   tanpi(+-inf) = nan.  Raises the "invalid" floating-point exception.
+//   tanpi(nan) = nan.  Raises the "invalid" floating-point exception.
+
+use crate::{
+    floor,
+    k_sinpi::{mul_pi, PI_HI, PI_LO},
+    k_tan::k_tan,
+};
+
+/// Tangent of π times x, tan(πx)
+///
+/// ```
+/// # use trig_const::tanpi;
+/// const TANPI_1_4: f64 = tanpi(0.25);
+/// assert_eq!(TANPI_1_4, 1.0);
+/// const TANPI_1: f64 = tanpi(1.0);
+/// assert_eq!(TANPI_1, 0.0);
+/// const TANPI_1_2: f64 = tanpi(0.5);
+/// assert_eq!(TANPI_1_2, f64::INFINITY);
+/// ```
+pub const fn tanpi(x: f64) -> f64 {
+    let hx = (x.to_bits() >> 32) as u32;
+    let ix = hx & 0x7fffffff;
+    let mut ax = x.abs();
+
+    let t = if ix < 0x3ff00000 {
+        /* |x| < 1 */
+        if ix < 0x3fe00000 {
+            /* |x| < 0.5 */
+            if ix < 0x3e200000 {
+                /* |x| < 0x1p-29 */
+                if x == 0.0 {
+                    return x;
+                }
+                // To avoid issues with subnormal values,
+                // scale the computation and rescale on
+                // return.
+                let hi = f64::from_bits((hx as u64) << 32) * f64::from_bits(0x4340000000000000);
+                let lo = x * f64::from_bits(0x4340000000000000) - hi;
+                let t = (PI_LO + PI_HI) * lo + PI_LO * hi + PI_HI * hi;
+                return t * f64::from_bits(0x3ca0000000000000);
+            }
+            k_tanpi(ax)
+        } else if ax == 0.5 {
+            f64::INFINITY
+        } else {
+            -k_tanpi(1.0 - ax)
+        }
+    } else if ix < 0x43300000 {
+        /* 1 <= |x| < 0x1p52 */
+        let n = floor(ax); /* Integer part of ax. */
+        let odd = n as u64 & 1 == 1;
+        ax -= n;
+        let ix = (ax.to_bits() >> 32) as u32;
+
+        if ax == 0.0 {
+            if odd {
+                -0.0
+            } else {
+                0.0
+            }
+        } else if ix < 0x3fe00000 {
+            /* |x| < 0.5 */
+            k_tanpi(ax)
+        } else if ax == 0.5 {
+            if odd {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            }
+        } else {
+            -k_tanpi(1.0 - ax)
+        }
+    } else if ix >= 0x7ff00000 {
+        /* x = +-inf or nan. */
+        return f64::NAN;
+    } else if ix < 0x43400000 && x.to_bits() & 1 == 1 {
+        /* odd integer in [0x1p52, 0x1p53) */
+        -0.0
+    } else {
+        /* |x| >= 0x1p52 is otherwise an even integer */
+        0.0
+    };
+
+    if hx & 0x80000000 != 0 {
+        -t
+    } else {
+        t
+    }
+}
+
+// The kernel for x in [0, 0.5).
+const fn k_tanpi(x: f64) -> f64 {
+    if x < 0.25 {
+        let (hi, lo) = mul_pi(x);
+        k_tan(hi, lo, 0)
+    } else if x > 0.25 {
+        let (hi, lo) = mul_pi(0.5 - x);
+        -k_tan(hi, lo, 1)
+    } else {
+        1.0
+    }
+}
@@ -0,0 +1,36 @@
+/* SPDX-License-Identifier: MIT
+ * origin: musl src/math/trunc.c */
+
+use crate::floor::{exp_unbiased, unsigned, SIG_BITS, SIG_MASK};
+
+/// Rounds toward zero to the nearest integer
+///
+/// ```
+/// # use trig_const::trunc;
+/// const TRUNC: f64 = trunc(-1.5);
+/// assert_eq!(TRUNC, -1.0);
+/// ```
+pub const fn trunc(x: f64) -> f64 {
+    nightly_exp!(trunc, trunc_inner, x)
+}
+
+pub(crate) const fn trunc_inner(x: f64) -> f64 {
+    let mut ix = x.to_bits();
+    let e = exp_unbiased(x);
+
+    // If the represented value has no fractional part, no truncation is needed.
+    if e >= SIG_BITS as i32 {
+        return x;
+    }
+
+    let m = if e >= 0 {
+        // |x| >= 1.0, clear the fractional bits
+        SIG_MASK >> unsigned(e)
+    } else {
+        // |x| < 1.0, keep only the sign
+        !(1 << 63)
+    };
+
+    ix &= !m;
+    f64::from_bits(ix)
+}
@@ -0,0 +1,125 @@
+//! FFT twiddle-factor tables
+//!
+//! The twiddle factor `W_n^k = e^(-2πik/n)` is computed from the exact rational
+//! angle `k/n` turns: `k` is reduced modulo `n` and folded into the first octant
+//! with integer arithmetic, so the only rounding is in `sinpi`/`cospi` of an
+//! angle of at most π/4. When `n` is a multiple of 8 the tables are exactly
+//! symmetric, and the entries at multiples of 30° and 45° are correctly rounded.
+//!
+//! Zeros are always `+0.0`, never `-0.0`.
+
+use core::f64::consts::FRAC_1_SQRT_2;
+
+use crate::{cospi, sinpi};
+
+/// sqrt(3)/2, correctly rounded
+const SQRT_3_2: f64 = 8.66025403784438596588e-01; /* 0x3FEBB67A, 0xE8584CAA */
+
+/// Twiddle factors of an `N`-point forward FFT
+///
+/// Entry `k` is `W_N^k = e^(-2πik/N)` as `(re, im)`.
+///
+/// ```
+/// # use trig_const::twiddles;
+/// const TW: [(f64, f64); 1024] = twiddles::<1024>();
+/// assert_eq!(TW[0], (1.0, 0.0));
+/// assert_eq!(TW[256], (0.0, -1.0));
+/// assert_eq!(TW[512], (-1.0, 0.0));
+/// assert_eq!(TW[128].0, -TW[128].1);
+/// ```
+pub const fn twiddles<const N: usize>() -> [(f64, f64); N] {
+    table(1, false)
+}
+
+/// Twiddle factors of an `N`-point inverse FFT
+///
+/// Entry `k` is `W_N^-k = e^(2πik/N)` as `(re, im)`, the complex conjugate of
+/// [`twiddles`]. The `1/N` normalization is not included.
+///
+/// ```
+/// # use trig_const::inverse_twiddles;
+/// const TW: [(f64, f64); 8] = inverse_twiddles::<8>();
+/// assert_eq!(TW[2], (0.0, 1.0));
+/// assert_eq!(TW[6], (0.0, -1.0));
+/// ```
+pub const fn inverse_twiddles<const N: usize>() -> [(f64, f64); N] {
+    table(1, true)
+}
+
+/// First half of the twiddle factors of a `2N`-point forward FFT
+///
+/// Entry `k` is `W_2N^k = e^(-πik/N)`. A radix-2 FFT of size `2N` only uses
+/// these, since `W_2N^(k + N) = -W_2N^k`.
+///
+/// ```
+/// # use trig_const::half_twiddles;
+/// const TW: [(f64, f64); 512] = half_twiddles::<512>();
+/// assert_eq!(TW[0], (1.0, 0.0));
+/// assert_eq!(TW[256], (0.0, -1.0));
+/// ```
+pub const fn half_twiddles<const N: usize>() -> [(f64, f64); N] {
+    table(2, false)
+}
+
+/// First quarter of the twiddle factors of a `4N`-point forward FFT
+///
+/// Entry `k` is `W_4N^k = e^(-πik/2N)`, for angles in `[0, π/2)`. The other
+/// quarters follow from `W_4N^(k + N) = -i W_4N^k`: rotate `(re, im)` to
+/// `(im, -re)`.
+///
+/// ```
+/// # use trig_const::quarter_twiddles;
+/// const TW: [(f64, f64); 256] = quarter_twiddles::<256>();
+/// assert_eq!(TW[0], (1.0, 0.0));
+/// assert_eq!(TW[128].0, -TW[128].1);
+/// ```
+pub const fn quarter_twiddles<const N: usize>() -> [(f64, f64); N] {
+    table(4, false)
+}
+
+/// The first `N` factors of an `N * fraction`-point table
+const fn table<const N: usize>(fraction: usize, inverse: bool) -> [(f64, f64); N] {
+    let n = N * fraction;
+    let mut out = [(0.0, 0.0); N];
+    let mut k = 0;
+    while k < N {
+        let (c, s) = cis_turns(k, n);
+        out[k] = if inverse { (c, s) } else { (c, -s + 0.0) };
+        k += 1;
+    }
+    out
+}
+
+/// `(cos(2πk/n), sin(2πk/n))`, with the angle reduced exactly
+const fn cis_turns(k: usize, n: usize) -> (f64, f64) {
+    /* 2πk/n = (π/2) * (quadrant + r/n), with 0 <= r < n */
+    let j = 4 * (k as u128 % n as u128);
+    let quadrant = j / n as u128;
+    let r = (j % n as u128) as u64;
+    let n = n as u64;
+
+    /* Fold into the first octant: an angle past π/4 is the complement of n - r */
+    let complement = 2 * r > n;
+    let t = if complement { n - r } else { r };
+    /* (π/2) * t/n = π * t/(2n), with t/(2n) in [0, 0.25] */
+    let x = t as f64 / (2 * n) as f64;
+    let (s, c) = if 2 * t == n {
+        /* π/4 exactly; cospi(0.25) is one ulp below the correctly rounded value */
+        (FRAC_1_SQRT_2, FRAC_1_SQRT_2)
+    } else if 3 * t == n {
+        /* π/6, where sinpi(1/6) is off by the rounding of 1/6 */
+        (0.5, SQRT_3_2)
+    } else {
+        (sinpi(x), cospi(x))
+    };
+    let (s, c) = if complement { (c, s) } else { (s, c) };
+
+    let (c, s) = match quadrant {
+        0 => (c, s),
+        1 => (-s, c),
+        2 => (-c, -s),
+        _ => (s, -c),
+    };
+    /* adding +0 turns -0 into +0 */
+    (c + 0.0, s + 0.0)
+}
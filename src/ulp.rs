@@ -0,0 +1,60 @@
+//! Unit in the last place
+
+/// The spacing of the floats at `x`, `2^(e - 52)` for `x` in `[2^e, 2^(e + 1))`
+///
+/// The sign of `x` is ignored. Zero and subnormals give the smallest subnormal,
+/// infinities give `+∞` and NaN propagates. For every finite `x`, `ulp(x)` is
+/// `next_up(|x|) - |x|`, except at `f64::MAX`, which keeps the spacing below it.
+///
+/// ```
+/// # use trig_const::ulp;
+/// const ULP_1: f64 = ulp(1.0);
+/// assert_eq!(ULP_1, f64::EPSILON);
+/// const ULP_0: f64 = ulp(0.0);
+/// assert_eq!(ULP_0, 5e-324);
+/// ```
+pub const fn ulp(x: f64) -> f64 {
+    let e = ((x.to_bits() >> 52) & 0x7ff) as i32;
+    if e == 0x7ff {
+        return x.abs();
+    }
+    if e > 52 {
+        f64::from_bits(((e - 52) as u64) << 52)
+    } else if e > 0 {
+        /* the spacing is subnormal */
+        f64::from_bits(1 << (e - 1))
+    } else {
+        f64::from_bits(1)
+    }
+}
+
+/// The number of representable values between `a` and `b`
+///
+/// Counts steps of [`next_up`](crate::next_up) from the smaller argument to
+/// the larger, so `+0.0` and `-0.0` are 0 apart and `f64::MAX` is 1 below
+/// `+∞`. If either argument is NaN, the result is `u64::MAX`.
+///
+/// ```
+/// # use trig_const::ulp_distance;
+/// const DISTANCE: u64 = ulp_distance(1.0, 1.0 + 2.0 * f64::EPSILON);
+/// assert_eq!(DISTANCE, 2);
+/// const ACROSS_ZERO: u64 = ulp_distance(-5e-324, 5e-324);
+/// assert_eq!(ACROSS_ZERO, 2);
+/// ```
+pub const fn ulp_distance(a: f64, b: f64) -> u64 {
+    if a.is_nan() || b.is_nan() {
+        return u64::MAX;
+    }
+    ordered(a).abs_diff(ordered(b))
+}
+
+/// Map `x` to an integer with the same ordering, with both zeros at 0
+const fn ordered(x: f64) -> i64 {
+    let bits = x.to_bits();
+    let abs = (bits & !(1 << 63)) as i64;
+    if bits == abs as u64 {
+        abs
+    } else {
+        -abs
+    }
+}
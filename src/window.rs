@@ -0,0 +1,336 @@
+//! Window functions
+//!
+//! Each window is a `const fn` returning `[f64; N]`, so tables can be built at
+//! compile time. Definitions and coefficients follow `scipy.signal.windows`.
+//!
+//! A [`Symmetric`](Symmetry::Symmetric) window is symmetric about its centre,
+//! with `w[n] == w[N - 1 - n]`, and is the one to use for FIR filter design. A
+//! [`Periodic`](Symmetry::Periodic) window is the first `N` points of the
+//! symmetric window of length `N + 1`, and is the one to use for spectral
+//! analysis, since it tiles without overlap. Windows are computed on the folded
+//! index, so the symmetry is exact. A window of length 1 is `[1.0]`.
+//!
+//! ```
+//! # use trig_const::window::{hann, Symmetry};
+//! const HANN: [f64; 5] = hann(Symmetry::Symmetric);
+//! assert_eq!(HANN, [0.0, 0.5, 1.0, 0.5, 0.0]);
+//! const PERIODIC: [f64; 4] = hann(Symmetry::Periodic);
+//! assert_eq!(PERIODIC, [0.0, 0.5, 1.0, 0.5]);
+//! ```
+
+use crate::{cospi, exp, i0e, sqrt};
+
+/// Hamming coefficients
+const HAMMING: [f64; 2] = [0.54, 0.46];
+/// Blackman coefficients
+const BLACKMAN: [f64; 3] = [0.42, 0.50, 0.08];
+/// 4-term Blackman-Harris coefficients
+const BLACKMAN_HARRIS: [f64; 4] = [0.35875, 0.48829, 0.14128, 0.01168];
+/// Nuttall's 4-term window with a continuous first derivative
+const NUTTALL: [f64; 4] = [0.3635819, 0.4891775, 0.1365995, 0.0106411];
+/// Flat-top coefficients, from Matlab's `flattopwin`
+const FLAT_TOP: [f64; 5] = [
+    0.21557895,
+    0.41663158,
+    0.277263158,
+    0.083578947,
+    0.006947368,
+];
+
+/// Whether a window is symmetric, or periodic for spectral analysis
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Symmetry {
+    /// `w[n] == w[N - 1 - n]`, for filter design
+    Symmetric,
+    /// The first `N` points of a symmetric window of length `N + 1`, for
+    /// spectral analysis
+    Periodic,
+}
+
+/// A window shape, with its parameter if it has one
+///
+/// Used to pick the window of the FIR design functions, such as
+/// [`fir_lowpass`](crate::fir_lowpass).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Window {
+    /// No taper, all ones
+    Rectangular,
+    /// [`hann`]
+    Hann,
+    /// [`hamming`]
+    Hamming,
+    /// [`blackman`]
+    Blackman,
+    /// [`blackman_harris`]
+    BlackmanHarris,
+    /// [`nuttall`]
+    Nuttall,
+    /// [`flat_top`]
+    FlatTop,
+    /// [`tukey`] with taper fraction `alpha`
+    Tukey(f64),
+    /// [`gaussian`] with standard deviation `sigma`, in samples
+    Gaussian(f64),
+    /// [`bartlett`]
+    Bartlett,
+    /// [`kaiser`] with shape parameter `beta`
+    Kaiser(f64),
+}
+
+impl Window {
+    /// The window of length `N`
+    ///
+    /// ```
+    /// # use trig_const::window::{hann, Symmetry, Window};
+    /// const W: [f64; 16] = Window::Hann.generate(Symmetry::Periodic);
+    /// assert_eq!(W, hann::<16>(Symmetry::Periodic));
+    /// ```
+    pub const fn generate<const N: usize>(self, symmetry: Symmetry) -> [f64; N] {
+        match self {
+            Window::Rectangular => [1.0; N],
+            Window::Hann => hann(symmetry),
+            Window::Hamming => hamming(symmetry),
+            Window::Blackman => blackman(symmetry),
+            Window::BlackmanHarris => blackman_harris(symmetry),
+            Window::Nuttall => nuttall(symmetry),
+            Window::FlatTop => flat_top(symmetry),
+            Window::Tukey(alpha) => tukey(alpha, symmetry),
+            Window::Gaussian(sigma) => gaussian(sigma, symmetry),
+            Window::Bartlett => bartlett(symmetry),
+            Window::Kaiser(beta) => kaiser(beta, symmetry),
+        }
+    }
+}
+
+/// Hann window, `0.5 - 0.5 cos(2πn/M)`
+///
+/// ```
+/// # use trig_const::window::{hann, Symmetry};
+/// const W: [f64; 1024] = hann(Symmetry::Periodic);
+/// assert_eq!(W[0], 0.0);
+/// assert_eq!(W[512], 1.0);
+/// assert_eq!(W[256], 0.5);
+/// ```
+pub const fn hann<const N: usize>(symmetry: Symmetry) -> [f64; N] {
+    cosine_sum(&[0.5, 0.5], symmetry)
+}
+
+/// Hamming window, `0.54 - 0.46 cos(2πn/M)`
+///
+/// ```
+/// # use trig_const::window::{hamming, Symmetry};
+/// const W: [f64; 3] = hamming(Symmetry::Symmetric);
+/// assert!((W[0] - 0.08).abs() < 1e-15);
+/// assert_eq!(W[1], 1.0);
+/// ```
+pub const fn hamming<const N: usize>(symmetry: Symmetry) -> [f64; N] {
+    cosine_sum(&HAMMING, symmetry)
+}
+
+/// Blackman window, `0.42 - 0.5 cos(2πn/M) + 0.08 cos(4πn/M)`
+///
+/// ```
+/// # use trig_const::window::{blackman, Symmetry};
+/// const W: [f64; 5] = blackman(Symmetry::Symmetric);
+/// assert!(W[0].abs() < 1e-15);
+/// assert!((W[1] - 0.34).abs() < 1e-15);
+/// ```
+pub const fn blackman<const N: usize>(symmetry: Symmetry) -> [f64; N] {
+    cosine_sum(&BLACKMAN, symmetry)
+}
+
+/// Minimum 4-term Blackman-Harris window, with sidelobes below -92 dB
+///
+/// ```
+/// # use trig_const::window::{blackman_harris, Symmetry};
+/// const W: [f64; 5] = blackman_harris(Symmetry::Symmetric);
+/// assert!((W[0] - 6e-5).abs() < 1e-15);
+/// assert_eq!(W[2], 1.0);
+/// ```
+pub const fn blackman_harris<const N: usize>(symmetry: Symmetry) -> [f64; N] {
+    cosine_sum(&BLACKMAN_HARRIS, symmetry)
+}
+
+/// Nuttall's 4-term window with a continuous first derivative
+///
+/// ```
+/// # use trig_const::window::{nuttall, Symmetry};
+/// const W: [f64; 5] = nuttall(Symmetry::Symmetric);
+/// assert!((W[0] - 3.628e-4).abs() < 1e-15);
+/// ```
+pub const fn nuttall<const N: usize>(symmetry: Symmetry) -> [f64; N] {
+    cosine_sum(&NUTTALL, symmetry)
+}
+
+/// Flat-top window, for accurate amplitudes of sinusoids between bins
+///
+/// The window goes slightly negative near its ends.
+///
+/// ```
+/// # use trig_const::window::{flat_top, Symmetry};
+/// const W: [f64; 5] = flat_top(Symmetry::Symmetric);
+/// assert!((W[0] + 4.21051e-4).abs() < 1e-15);
+/// assert!((W[2] - 1.000000003).abs() < 1e-15);
+/// ```
+pub const fn flat_top<const N: usize>(symmetry: Symmetry) -> [f64; N] {
+    cosine_sum(&FLAT_TOP, symmetry)
+}
+
+/// Tukey (tapered cosine) window
+///
+/// A fraction `alpha` of the window is a cosine taper, split between the two
+/// ends, and the rest is flat. `alpha <= 0` gives a rectangular window and
+/// `alpha >= 1` a Hann window.
+///
+/// ```
+/// # use trig_const::window::{tukey, Symmetry};
+/// const W: [f64; 9] = tukey(0.5, Symmetry::Symmetric);
+/// assert_eq!(W, [0.0, 0.5, 1.0, 1.0, 1.0, 1.0, 1.0, 0.5, 0.0]);
+/// ```
+pub const fn tukey<const N: usize>(alpha: f64, symmetry: Symmetry) -> [f64; N] {
+    let mut w = [1.0; N];
+    if N <= 1 || alpha <= 0.0 {
+        return w;
+    }
+    if alpha >= 1.0 {
+        return hann(symmetry);
+    }
+    let m = span(N, symmetry);
+    let mut n = 0;
+    while n < N {
+        /* x = n / M, folded to [0, 0.5] */
+        let x = fold(n, m) as f64 / m as f64;
+        if x < alpha / 2.0 {
+            w[n] = 0.5 - 0.5 * cospi(2.0 * x / alpha);
+        }
+        n += 1;
+    }
+    w
+}
+
+/// Gaussian window, `exp(-((n - M/2) / σ)^2 / 2)`
+///
+/// `sigma` is the standard deviation in samples.
+///
+/// ```
+/// # use trig_const::window::{gaussian, Symmetry};
+/// const W: [f64; 5] = gaussian(1.0, Symmetry::Symmetric);
+/// assert_eq!(W[2], 1.0);
+/// const EXP_HALF: f64 = trig_const::exp(-0.5);
+/// assert_eq!(W[1], EXP_HALF);
+/// ```
+pub const fn gaussian<const N: usize>(sigma: f64, symmetry: Symmetry) -> [f64; N] {
+    let mut w = [1.0; N];
+    if N <= 1 {
+        return w;
+    }
+    let m = span(N, symmetry);
+    let mut n = 0;
+    while n < N {
+        /* distance from the centre, exact */
+        let d = (m - 2 * fold(n, m)) as f64 * 0.5 / sigma;
+        w[n] = exp(-0.5 * d * d);
+        n += 1;
+    }
+    w
+}
+
+/// Bartlett (triangular) window, zero at both ends
+///
+/// ```
+/// # use trig_const::window::{bartlett, Symmetry};
+/// const W: [f64; 5] = bartlett(Symmetry::Symmetric);
+/// assert_eq!(W, [0.0, 0.5, 1.0, 0.5, 0.0]);
+/// ```
+pub const fn bartlett<const N: usize>(symmetry: Symmetry) -> [f64; N] {
+    let mut w = [1.0; N];
+    if N <= 1 {
+        return w;
+    }
+    let m = span(N, symmetry);
+    let mut n = 0;
+    while n < N {
+        w[n] = (2 * fold(n, m)) as f64 / m as f64;
+        n += 1;
+    }
+    w
+}
+
+/// Kaiser window, `I0(β sqrt(1 - (2n/M - 1)^2)) / I0(β)`
+///
+/// `beta` trades main-lobe width for sidelobe level: 0 is rectangular, about 5
+/// is close to a Hamming window and about 8.6 to a Blackman window. The ratio
+/// is computed with [`i0e`], so large `beta` does not overflow.
+///
+/// ```
+/// # use trig_const::window::{kaiser, Symmetry};
+/// const W: [f64; 5] = kaiser(14.0, Symmetry::Symmetric);
+/// assert_eq!(W[2], 1.0);
+/// assert!((W[0] - 7.726866835270368e-06).abs() < 1e-20);
+/// ```
+pub const fn kaiser<const N: usize>(beta: f64, symmetry: Symmetry) -> [f64; N] {
+    let mut w = [1.0; N];
+    if N <= 1 {
+        return w;
+    }
+    let m = span(N, symmetry);
+    let denom = i0e(beta);
+    let mut n = 0;
+    while n < N {
+        let j = fold(n, m) as u128;
+        /* 1 - (2n/M - 1)^2 = 4n(M - n) / M^2 */
+        let r = (4 * j * (m as u128 - j)) as f64 / (m as u128 * m as u128) as f64;
+        let a = beta * sqrt(r);
+        /* I0(a) / I0(beta), with the exponential scaling factored out */
+        w[n] = i0e(a) / denom * exp(a.abs() - beta.abs());
+        n += 1;
+    }
+    w
+}
+
+/// Sum of cosines, `a[0] - a[1] cos(2πn/M) + a[2] cos(4πn/M) - ...`
+const fn cosine_sum<const N: usize>(a: &[f64], symmetry: Symmetry) -> [f64; N] {
+    let mut w = [1.0; N];
+    if N <= 1 {
+        return w;
+    }
+    let m = span(N, symmetry);
+    let mut n = 0;
+    while n < N {
+        let j = fold(n, m);
+        /* smallest terms first */
+        let mut sum = 0.0;
+        let mut k = a.len();
+        while k > 0 {
+            k -= 1;
+            let t = a[k] * cos_turns(k * j, m);
+            sum += if k % 2 == 1 { -t } else { t };
+        }
+        w[n] = sum;
+        n += 1;
+    }
+    w
+}
+
+/// The denominator `M` of the window phase `n / M`
+const fn span(len: usize, symmetry: Symmetry) -> usize {
+    match symmetry {
+        Symmetry::Symmetric => len - 1,
+        Symmetry::Periodic => len,
+    }
+}
+
+/// `n` mirrored into the first half of `0..=m`
+const fn fold(n: usize, m: usize) -> usize {
+    if 2 * n > m {
+        m - n
+    } else {
+        n
+    }
+}
+
+/// `cos(2πp/m)`, with the angle reduced exactly
+const fn cos_turns(p: usize, m: usize) -> f64 {
+    let r = fold(p % m, m);
+    cospi(2.0 * r as f64 / m as f64)
+}
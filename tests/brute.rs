@@ -1,6 +1,23 @@
 use core::f64::consts::PI;
 
-use trig_const::{acos, acosh, asin, asinh, atan, atan2, cos, ln, pow, sin, sqrt, tan};
+use trig_const::{
+    acos, acosd, acosh, acospi, asin, asind, asinh, asinpi, atan, atan2, atan2d, atan2pi, atand,
+    atanpi, bessel_i0, bessel_i1, cbrt, ceil,
+    complex::Complex,
+    cos, cosd, cosf, cospi, erf, erf_inv, erfc, erfc_inv, exp10, exp2, expf, expi, expm1,
+    fir_bandpass, fir_bandstop, fir_highpass, fir_lowpass, floor, fma, fmod, frexp, half_twiddles,
+    hypot, i0e, i1e, ilogb, inverse_twiddles, j0, j1, jn, ldexp, lgamma, lgamma_r, ln, lnf, log10,
+    log1p, log2, logb, modf, nearbyint, next_down, next_up, nextafter, pow, powf, quarter_twiddles,
+    reduce::{rem_2pi, rem_pi, rem_pi_over_2k, rem_pio2},
+    remainder, remquo, rint, round, round_ties_even, scalbn, sin, sinc, sinc_unnormalized, sincos,
+    sind, sinf, sinpi, sqrt, sqrtf, tan, tand, tanf, tanpi, tgamma, trunc, twiddles, ulp,
+    ulp_distance,
+    window::{
+        bartlett, blackman, blackman_harris, flat_top, gaussian, hamming, hann, kaiser, nuttall,
+        tukey, Symmetry, Window,
+    },
+    y0, y1, yn,
+};
 
 fn float_loop(start: f64, stop: f64, step: f64) -> impl Iterator<Item = f64> {
     core::iter::successors(Some(start), move |prev| {
@@ -9,6 +26,18 @@ fn float_loop(start: f64, stop: f64, step: f64) -> impl Iterator<Item = f64> {
     })
 }
 
+/// Walk the full exponent range, including subnormals, in a stride that hits
+/// every exponent; with `signed`, each value is followed by its negation
+fn bit_walk(signed: bool) -> impl Iterator<Item = f64> {
+    core::iter::successors(Some(1u64), |bits| {
+        let next = bits + 0x0000_0f1f_3a5b_1c2d;
+        (next < f64::INFINITY.to_bits()).then_some(next)
+    })
+    .map(f64::from_bits)
+    .flat_map(move |x| [Some(x), signed.then_some(-x)])
+    .flatten()
+}
+
 macro_rules! float_eq {
     ($lhs:expr, $rhs:expr, $tol:expr) => {
         if !$lhs.is_nan() && !$rhs.is_nan() {
@@ -41,6 +70,89 @@ fn test_tan() {
     }
 }
 
+#[test]
+fn test_sincos() {
+    for x in float_loop(-8.0 * PI, 8.0 * PI, 0.001).chain([
+        0.0,
+        -0.0,
+        1e-9,
+        1e-300,
+        5e-324,
+        1e22,
+        -1e300,
+        f64::MAX,
+    ]) {
+        let (s, c) = sincos(x);
+        assert_eq!(s.to_bits(), sin(x).to_bits(), "x: {x:e}");
+        assert_eq!(c.to_bits(), cos(x).to_bits(), "x: {x:e}");
+    }
+    let (s, c) = sincos(f64::INFINITY);
+    assert!(s.is_nan() && c.is_nan());
+}
+
+#[test]
+fn test_pi_scaled() {
+    for x in float_loop(-8.0, 8.0, 0.001) {
+        float_eq!(sinpi(x), (x * PI).sin(), 1e-12);
+        float_eq!(cospi(x), (x * PI).cos(), 1e-12);
+        let t = (x * PI).tan();
+        if t.abs() < 1e3 {
+            // Near the poles the reference is dominated by the rounding of x * PI
+            float_eq!(tanpi(x), t, 1e-12 * t.abs().max(1.0));
+        }
+    }
+    for x in float_loop(-1.0, 1.0, 0.001) {
+        float_eq!(asinpi(x), x.asin() / PI);
+        float_eq!(acospi(x), x.acos() / PI);
+        float_eq!(atanpi(4.0 * x), (4.0 * x).atan() / PI);
+        float_eq!(atan2pi(x, 0.3), x.atan2(0.3) / PI);
+    }
+}
+
+#[test]
+fn test_degrees() {
+    for x in float_loop(-1000.0, 1000.0, 0.0137) {
+        let r = x.to_radians();
+        float_eq!(sind(x), r.sin(), 1e-12);
+        float_eq!(cosd(x), r.cos(), 1e-12);
+        let t = r.tan();
+        if t.abs() < 1e2 {
+            // Near the poles the reference is dominated by the rounding of to_radians
+            float_eq!(tand(x), t, 1e-12 * t.abs().max(1.0));
+        }
+    }
+    for x in float_loop(-1.0, 1.0, 0.001) {
+        float_eq!(asind(x), x.asin().to_degrees(), 1e-12);
+        float_eq!(acosd(x), x.acos().to_degrees(), 1e-12);
+        float_eq!(atand(4.0 * x), (4.0 * x).atan().to_degrees(), 1e-12);
+        float_eq!(atan2d(x, -0.3), x.atan2(-0.3).to_degrees(), 1e-12);
+    }
+}
+
+#[test]
+fn test_sinf() {
+    for x in float_loop(-8.0 * PI, 8.0 * PI, 0.01) {
+        let x = x as f32;
+        float_eq!(sinf(x), x.sin(), 1e-6);
+    }
+}
+
+#[test]
+fn test_cosf() {
+    for x in float_loop(-8.0 * PI, 8.0 * PI, 0.01) {
+        let x = x as f32;
+        float_eq!(cosf(x), x.cos(), 1e-6);
+    }
+}
+
+#[test]
+fn test_tanf() {
+    for x in float_loop(-8.0 * PI, 8.0 * PI, 0.01) {
+        let x = x as f32;
+        float_eq!(tanf(x), x.tan(), 1e-6 * x.tan().abs().max(1.0));
+    }
+}
+
 #[test]
 fn test_asin() {
     for x in float_loop(-1.0, 1.0, 0.01) {
@@ -92,11 +204,1261 @@ fn test_ln() {
     }
 }
 
+#[test]
+fn test_lnf() {
+    for x in float_loop(1.0, 10.0, 0.01) {
+        let x = x as f32;
+        float_eq!(lnf(x), x.ln(), 1e-6);
+    }
+}
+
+#[test]
+fn test_expf() {
+    for x in float_loop(-10.0, 10.0, 0.01) {
+        let x = x as f32;
+        float_eq!(expf(x), x.exp(), 1e-6 * x.exp());
+    }
+}
+
+#[test]
+fn test_exp2() {
+    for x in float_loop(-1080.0, 1023.9, 0.0731) {
+        float_eq!(exp2(x), x.exp2(), (1e-15 * x.exp2()).max(1e-320));
+    }
+}
+
+#[test]
+fn test_exp10() {
+    for x in float_loop(-330.0, 308.2, 0.0173) {
+        let expected = 10f64.powf(x);
+        float_eq!(exp10(x), expected, (2e-15 * expected).max(1e-320));
+    }
+}
+
+#[test]
+fn test_log2() {
+    for x in float_loop(0.0137, 100.0, 0.0137) {
+        float_eq!(log2(x), x.log2(), 1e-15 * x.log2().abs().max(1.0));
+    }
+}
+
+#[test]
+fn test_log10() {
+    for x in float_loop(0.0137, 100.0, 0.0137) {
+        float_eq!(log10(x), x.log10(), 1e-15 * x.log10().abs().max(1.0));
+    }
+}
+
+#[test]
+fn test_log1p() {
+    for x in float_loop(-0.99, 10.0, 0.0137) {
+        float_eq!(log1p(x), x.ln_1p(), 1e-15 * x.ln_1p().abs().max(1e-300));
+    }
+}
+
+#[test]
+fn test_log1p_tiny() {
+    // ln(1 + x) = x - x^2/2 + x^3/3 - ..., so the quadratic term decides the last bits
+    for x in float_loop(-1e-10, 1e-10, 1.37e-13) {
+        let expected = x - x * x / 2.0;
+        float_eq!(log1p(x), expected, 1e-16 * x.abs());
+        float_eq!(log1p(x), x.ln_1p(), 1e-16 * x.abs());
+    }
+    for x in [1e-300, -1e-300, 5e-324, 1e-20, -1e-20] {
+        assert_eq!(log1p(x), x);
+    }
+}
+
+#[test]
+fn test_expm1() {
+    for x in float_loop(-40.0, 700.0, 0.0731) {
+        float_eq!(expm1(x), x.exp_m1(), 1e-15 * x.exp_m1().abs().max(1e-300));
+    }
+}
+
+#[test]
+fn test_expm1_tiny() {
+    // e^x - 1 = x + x^2/2 + x^3/6 + ..., so the quadratic term decides the last bits
+    for x in float_loop(-1e-10, 1e-10, 1.37e-13) {
+        let expected = x + x * x / 2.0;
+        float_eq!(expm1(x), expected, 1e-16 * x.abs());
+        float_eq!(expm1(x), x.exp_m1(), 1e-16 * x.abs());
+    }
+    for x in [1e-300, -1e-300, 5e-324, 1e-20, -1e-20] {
+        assert_eq!(expm1(x), x);
+    }
+}
+
+#[test]
+fn test_erf() {
+    for x in float_loop(-6.0, 6.0, 0.0137) {
+        float_eq!(erf(x) + erfc(x), 1.0_f64, 4e-16);
+        assert_eq!(erf(-x), -erf(x));
+        float_eq!(erfc(-x), 2.0 - erfc(x), 4.5e-16);
+    }
+    // Tabulated values of the normal distribution
+    float_eq!(erf(1.0), 0.8427007929497149_f64, 2e-16);
+    float_eq!(erf(0.5), 0.5204998778130465_f64, 2e-16);
+    float_eq!(erfc(3.0), 2.209049699858544e-5_f64, 2e-20);
+    float_eq!(erfc(10.0), 2.088487583762545e-45_f64, 1e-59);
+    float_eq!(erfc(26.0), 5.663192408856143e-296_f64, 1e-309);
+    assert_eq!(erfc(30.0), 0.0);
+    assert_eq!(erfc(-30.0), 2.0);
+    assert_eq!(erf(f64::INFINITY), 1.0);
+    assert_eq!(erfc(f64::NEG_INFINITY), 2.0);
+    assert!(erf(f64::NAN).is_nan());
+    assert!(erfc(f64::NAN).is_nan());
+}
+
+#[test]
+fn test_erf_inv() {
+    for x in float_loop(-5.5, 5.5, 0.0137) {
+        let y = erf(x);
+        // erf flattens out, so the round trip is only as good as dy / erf'(x)
+        let tol = 4e-16 * (1.0 + y.abs() * (x * x).exp());
+        float_eq!(erf_inv(y), x, tol);
+        let tol = if x < 0.0 { tol } else { 4e-16 * x.max(1.0) };
+        float_eq!(erfc_inv(erfc(x)), x, tol);
+    }
+    for x in float_loop(0.5, 26.5, 0.0173) {
+        float_eq!(erfc_inv(erfc(x)), x, 4e-16 * x);
+    }
+    for z in [1e-20, 1e-100, 1e-300, 1e-310, 5e-324] {
+        float_eq!(erfc(erfc_inv(z)), z, 1e-12 * z.max(1e-300));
+    }
+    float_eq!(erf_inv(0.5), 0.4769362762044699_f64, 1e-16);
+    float_eq!(
+        erf_inv(1.0 - f64::EPSILON / 2.0),
+        5.863584748755168_f64,
+        1e-15
+    );
+    float_eq!(erf_inv(1e-300), 8.86226925452758e-301_f64, 2e-316);
+    assert_eq!(erf_inv(-0.0).to_bits(), (-0.0f64).to_bits());
+    assert_eq!(erf_inv(1.0), f64::INFINITY);
+    assert_eq!(erf_inv(-1.0), f64::NEG_INFINITY);
+    assert!(erf_inv(1.5).is_nan());
+    assert!(erf_inv(f64::NAN).is_nan());
+    assert_eq!(erfc_inv(0.0), f64::INFINITY);
+    assert_eq!(erfc_inv(1.0), 0.0);
+    assert_eq!(erfc_inv(2.0), f64::NEG_INFINITY);
+    assert_eq!(erfc_inv(1.5), -erf_inv(0.5));
+    assert!(erfc_inv(-0.5).is_nan());
+    assert!(erfc_inv(2.5).is_nan());
+}
+
+#[test]
+fn test_bessel() {
+    for (n, x, j, y) in [
+        (0, 0.5, 0.9384698072408129_f64, -0.44451873350670656_f64),
+        (0, 10.0, -0.24593576445134835, 0.05567116728359939),
+        (1, 3.0, 0.3390589585259365, 0.3246744247918),
+        (2, 5.0, 0.046565116277752214, 0.36766288260552454),
+        (5, 1.0, 0.00024975773021123444, -260.4058666258122),
+        (10, 20.0, 0.1864825580239451, -0.0438946535156584),
+        (50, 10.0, 1.7845136078715953e-30, -3.6410665018007404e+27),
+        (3, 100.0, 0.07628420172033194, 0.02344578668776091),
+    ] {
+        float_eq!(jn(n, x), j, 1e-15 * j.abs());
+        float_eq!(yn(n, x), y, 1e-15 * y.abs());
+    }
+    for x in float_loop(0.01, 50.0, 0.0173) {
+        assert_eq!(jn(0, x), j0(x));
+        assert_eq!(jn(1, x), j1(x));
+        assert_eq!(yn(0, x), y0(x));
+        assert_eq!(yn(1, x), y1(x));
+        assert_eq!(jn(-3, x), -jn(3, x));
+        assert_eq!(j0(-x), j0(x));
+        assert_eq!(j1(-x), -j1(x));
+        // Wronskian
+        float_eq!(j1(x) * y0(x) - j0(x) * y1(x), 2.0 / (PI * x), 1e-14 / x);
+        // Recurrence J(n-1) + J(n+1) = 2n/x J(n), for orders where the terms do not cancel
+        for n in 1..4 {
+            let lhs = jn(n - 1, x) + jn(n + 1, x);
+            let rhs = 2.0 * n as f64 / x * jn(n, x);
+            float_eq!(lhs, rhs, 1e-14 * (1.0 + jn(n - 1, x).abs() + rhs.abs()));
+            let lhs = yn(n - 1, x) + yn(n + 1, x);
+            let rhs = 2.0 * n as f64 / x * yn(n, x);
+            float_eq!(lhs, rhs, 1e-14 * (1.0 + yn(n - 1, x).abs() + rhs.abs()));
+        }
+    }
+    assert_eq!(j0(0.0), 1.0);
+    assert_eq!(j1(-0.0).to_bits(), (-0.0f64).to_bits());
+    assert_eq!(jn(2, 0.0), 0.0);
+    assert_eq!(j0(f64::INFINITY), 0.0);
+    assert_eq!(y0(0.0), f64::NEG_INFINITY);
+    assert_eq!(y1(0.0), f64::NEG_INFINITY);
+    assert_eq!(yn(5, 0.0), f64::NEG_INFINITY);
+    assert!(y0(-1.0).is_nan());
+    assert!(yn(2, -1.0).is_nan());
+    assert!(jn(2, f64::NAN).is_nan());
+}
+
+#[test]
+fn test_bessel_i() {
+    // mpmath reference values, including the worst points of a dense scan;
+    // the documented bound is a relative error below 6e-16
+    for (x, i0, i1) in [
+        (0.0213, 1.0001134257162063_f64, 0.010650603986229997_f64),
+        (0.0506, 1.0006401924360873, 0.02530809800236231),
+        (0.5, 1.0634833707413236, 0.2578943053908963),
+        (3.0, 4.8807925858650245, 3.9533702174026093),
+        (4.7310376060335235, 21.440233565850477, 19.012079169428247),
+        (6.180595779599985, 79.29708939944292, 72.56297623694441),
+        (6.97, 163.97795386039024, 151.71167202508107),
+        (7.79, 351.3891042332634, 327.99188342061683),
+        (8.0, 427.5641157218048, 399.8731367825601),
+        (8.5, 683.1619269901156, 641.6199025400667),
+        (9.134099238048186, 1241.024225487269, 1170.9814006583752),
+        (20.0, 43558282.559553534, 42454973.38512777),
+        (100.0, 1.0737517071310738e+42, 1.0683693903381625e+42),
+        (644.5, 1.2565404910700478e+278, 1.255565294283234e+278),
+        (700.0, 1.5295933476718737e+302, 1.5285003902339006e+302),
+        (712.5, 4.068293090664341e+307, 4.0654371450013245e+307),
+    ] {
+        float_eq!(bessel_i0(x), i0, 6e-16 * i0);
+        float_eq!(bessel_i1(x), i1, 6e-16 * i1);
+    }
+    for (x, i0_e, i1_e) in [
+        (0.0213, 0.9790362782373291_f64, 0.01042614509468364_f64),
+        (0.0506, 0.9512674626432829, 0.024059367545914794),
+        (0.5, 0.6450352704491501, 0.1564208031848717),
+        (3.0, 0.2430003541618254, 0.19682671329730086),
+        (4.7310376060335235, 0.18904534426333933, 0.1676355362784433),
+        (6.180595779599985, 0.1640811173756767, 0.15014692608813657),
+        (6.97, 0.154082360837973, 0.1425563134676202),
+        (7.79, 0.14542339418564976, 0.13574038687524803),
+        (8.0, 0.14343178185685032, 0.13414249329269817),
+        (8.5, 0.13900184305484758, 0.13054935509459586),
+        (9.134099238048186, 0.13393416146925108, 0.1263749802560784),
+        (20.0, 0.08978031188482602, 0.08750622218328867),
+        (100.0, 0.03994437929909668, 0.03974415302513025),
+        (644.5, 0.015717479454389824, 0.015705281172225687),
+        (700.0, 0.015081295651531358, 0.015070519444716848),
+        (712.5, 0.014948371312550616, 0.014937877541510075),
+    ] {
+        float_eq!(i0e(x), i0_e, 6e-16 * i0_e);
+        float_eq!(i1e(x), i1_e, 6e-16 * i1_e);
+    }
+    for x in float_loop(0.0, 50.0, 0.0173) {
+        assert_eq!(bessel_i0(-x), bessel_i0(x));
+        assert_eq!(bessel_i1(-x), -bessel_i1(x));
+        assert_eq!(i0e(-x), i0e(x));
+        assert_eq!(i1e(-x), -i1e(x));
+        float_eq!(i0e(x), bessel_i0(x) * (-x).exp(), 1e-15 * i0e(x));
+        // I0' = I1
+        let h = 1e-5;
+        let d = (bessel_i0(x + h) - bessel_i0(x - h)) / (2.0 * h);
+        float_eq!(d, bessel_i1(x), 1e-8 * bessel_i0(x));
+    }
+    assert_eq!(bessel_i0(0.0), 1.0);
+    assert_eq!(bessel_i1(-0.0).to_bits(), (-0.0f64).to_bits());
+    assert!(bessel_i0(713.0).is_finite());
+    assert_eq!(bessel_i0(715.0), f64::INFINITY);
+    assert_eq!(bessel_i0(f64::NEG_INFINITY), f64::INFINITY);
+    assert_eq!(bessel_i1(f64::NEG_INFINITY), f64::NEG_INFINITY);
+    assert_eq!(i0e(f64::INFINITY), 0.0);
+    assert!(bessel_i0(f64::NAN).is_nan());
+    assert!(i1e(f64::NAN).is_nan());
+}
+
 #[test]
 fn test_sqrt() {
     for x in float_loop(0.0, 10.0, 0.01) {
-        float_eq!(sqrt(x), x.sqrt());
+        assert_eq!(sqrt(x), x.sqrt());
+    }
+}
+
+#[test]
+fn test_sqrt_bits() {
+    for x in bit_walk(false) {
+        assert_eq!(sqrt(x).to_bits(), x.sqrt().to_bits(), "x: {x:e}");
     }
+    for x in [
+        1e300,
+        1e-300,
+        5e-324,
+        f64::MAX,
+        f64::MIN_POSITIVE,
+        -0.0,
+        f64::INFINITY,
+    ] {
+        assert_eq!(sqrt(x).to_bits(), x.sqrt().to_bits(), "x: {x:e}");
+    }
+    assert!(sqrt(-1.0).is_nan());
+    assert!(sqrt(f64::NAN).is_nan());
+}
+
+#[test]
+fn test_cbrt() {
+    for x in float_loop(-100.0, 100.0, 0.0137) {
+        float_eq!(cbrt(x), x.cbrt(), 1e-15 * x.cbrt().abs());
+    }
+    for k in -300..=300 {
+        let x = 10f64.powi(k);
+        float_eq!(cbrt(x), x.cbrt(), 1e-15 * x.cbrt());
+        let cube = (k as f64) * (k as f64) * (k as f64);
+        assert_eq!(cbrt(cube), k as f64);
+    }
+    for x in [5e-324, 1e-310, f64::MAX, -0.0, f64::INFINITY] {
+        assert_eq!(cbrt(x), x.cbrt(), "x: {x:e}");
+    }
+    assert!(cbrt(f64::NAN).is_nan());
+}
+
+#[test]
+fn test_hypot() {
+    for x in float_loop(-10.0, 10.0, 0.137) {
+        for y in float_loop(-10.0, 10.0, 0.137) {
+            let h = x.hypot(y);
+            float_eq!(hypot(x, y), h, 2.3e-16 * h);
+        }
+    }
+    for k in [-1000, -700, -500, -300, 0, 300, 500, 700, 1000] {
+        let s = 2f64.powi(k);
+        assert_eq!(hypot(3.0 * s, 4.0 * s), 5.0 * s);
+    }
+    assert_eq!(hypot(f64::MAX, f64::MAX), f64::INFINITY);
+    assert_eq!(hypot(5e-324, 0.0), 5e-324);
+    assert_eq!(hypot(f64::NEG_INFINITY, f64::NAN), f64::INFINITY);
+    assert_eq!(hypot(f64::NAN, f64::INFINITY), f64::INFINITY);
+    assert!(hypot(f64::NAN, 1.0).is_nan());
+}
+
+/// IEEE remainder derived from the exact `%`; `x % (2|y|)` tells the parity
+/// of the quotient in the halfway case
+fn remainder_ref(x: f64, y: f64) -> f64 {
+    let ay = y.abs();
+    let r = x % ay;
+    let ar = r.abs();
+    if 2.0 * ar > ay || (2.0 * ar == ay && (x % (2.0 * ay)).abs() != ar) {
+        r - ay.copysign(r)
+    } else {
+        r
+    }
+}
+
+/// Sample finite values of both signs across the full exponent range
+fn float_bits_sample() -> impl Iterator<Item = f64> {
+    let step = 0x0003_1f1f_3a5b_1c2d;
+    (1..f64::INFINITY.to_bits() / step)
+        .map(move |k| f64::from_bits(k * step))
+        .flat_map(|x| [x, -x])
+}
+
+#[test]
+fn test_fmod() {
+    for x in float_loop(-10.0, 10.0, 0.137) {
+        for y in float_loop(-10.0, 10.0, 0.173) {
+            assert_eq!(
+                fmod(x, y).to_bits(),
+                (x % y).to_bits(),
+                "x: {x:e}, y: {y:e}"
+            );
+        }
+    }
+    for x in float_bits_sample() {
+        for y in [1.0, -3.0, 0.1, 2.0 * PI, 1e-310, 5e-324, 1e300, f64::MAX] {
+            assert_eq!(
+                fmod(x, y).to_bits(),
+                (x % y).to_bits(),
+                "x: {x:e}, y: {y:e}"
+            );
+            assert_eq!(
+                fmod(y, x).to_bits(),
+                (y % x).to_bits(),
+                "x: {y:e}, y: {x:e}"
+            );
+        }
+    }
+    assert_eq!(fmod(-0.0, 1.0).to_bits(), (-0.0f64).to_bits());
+    assert_eq!(fmod(-4.0, 2.0).to_bits(), (-0.0f64).to_bits());
+    assert_eq!(fmod(1.5, f64::INFINITY), 1.5);
+    assert!(fmod(1.0, 0.0).is_nan());
+    assert!(fmod(f64::INFINITY, 1.0).is_nan());
+    assert!(fmod(f64::NAN, 1.0).is_nan());
+    assert!(fmod(1.0, f64::NAN).is_nan());
+}
+
+#[test]
+fn test_remainder() {
+    for x in float_loop(-10.0, 10.0, 0.137) {
+        for y in float_loop(-10.0, 10.0, 0.173) {
+            let r = remainder_ref(x, y);
+            assert_eq!(remainder(x, y).to_bits(), r.to_bits(), "x: {x:e}, y: {y:e}");
+            let (rq, q) = remquo(x, y);
+            assert_eq!(rq.to_bits(), r.to_bits(), "x: {x:e}, y: {y:e}");
+            let n = ((x - r) / y).round() as i32;
+            assert_eq!(q & 7, n & 7, "x: {x:e}, y: {y:e}");
+            assert!(q == 0 || (q < 0) == (n < 0), "x: {x:e}, y: {y:e}");
+        }
+    }
+    for x in float_bits_sample() {
+        for y in [1.0, -3.0, 0.1, 2.0 * PI, 1e-310, 5e-324, 1e300, f64::MAX] {
+            let r = remainder_ref(x, y);
+            assert_eq!(remainder(x, y).to_bits(), r.to_bits(), "x: {x:e}, y: {y:e}");
+            assert_eq!(remquo(x, y).0.to_bits(), r.to_bits(), "x: {x:e}, y: {y:e}");
+        }
+    }
+    // Halfway cases round the quotient to even
+    assert_eq!(remquo(5.0, 2.0), (1.0, 2));
+    assert_eq!(remquo(7.0, 2.0), (-1.0, 4));
+    assert_eq!(remquo(-7.0, 2.0), (1.0, -4));
+    assert_eq!(remainder(-4.0, 2.0).to_bits(), (-0.0f64).to_bits());
+    assert_eq!(remainder(1.5, f64::INFINITY), 1.5);
+    assert!(remainder(1.0, 0.0).is_nan());
+    assert!(remainder(f64::INFINITY, 1.0).is_nan());
+    assert!(remquo(f64::NAN, 1.0).0.is_nan());
+    // Quotient bits wrap without overflowing
+    let _ = remquo(-2.0000000000000004, 8.406091369059082e-286);
+}
+
+#[test]
+fn test_decompose() {
+    for x in float_bits_sample() {
+        let (m, e) = frexp(x);
+        assert!((0.5..1.0).contains(&m.abs()), "x: {x:e}");
+        assert_eq!(scalbn(m, e), x, "x: {x:e}");
+        assert_eq!(ldexp(m, e), x, "x: {x:e}");
+        assert_eq!(ilogb(x), e - 1, "x: {x:e}");
+        assert_eq!(logb(x), (e - 1) as f64, "x: {x:e}");
+
+        let (frac, int) = modf(x);
+        assert_eq!(int.to_bits(), x.trunc().to_bits(), "x: {x:e}");
+        assert_eq!(frac + int, x, "x: {x:e}");
+        assert_eq!(frac.is_sign_negative(), x.is_sign_negative(), "x: {x:e}");
+
+        for n in [-1022, -60, -1, 0, 1, 60, 1023] {
+            let expected = x * f64::from_bits(((n + 1023) as u64) << 52);
+            assert_eq!(
+                scalbn(x, n).to_bits(),
+                expected.to_bits(),
+                "x: {x:e}, n: {n}"
+            );
+        }
+    }
+    assert_eq!(scalbn(5e-324, 2097), 2f64.powi(1023));
+    assert_eq!(scalbn(f64::MAX, -2098), 5e-324);
+    assert_eq!(scalbn(1.0, 1024), f64::INFINITY);
+    assert_eq!(scalbn(-1.0, -1075).to_bits(), (-0.0f64).to_bits());
+    assert_eq!(frexp(-0.0).0.to_bits(), (-0.0f64).to_bits());
+    assert_eq!(frexp(f64::NEG_INFINITY), (f64::NEG_INFINITY, 0));
+    assert_eq!(ilogb(0.0), i32::MIN);
+    assert_eq!(ilogb(f64::INFINITY), i32::MAX);
+    assert_eq!(ilogb(f64::NAN), i32::MIN);
+    assert_eq!(logb(-0.0), f64::NEG_INFINITY);
+    assert_eq!(logb(f64::NEG_INFINITY), f64::INFINITY);
+    assert!(logb(f64::NAN).is_nan());
+    assert_eq!(modf(f64::INFINITY), (0.0, f64::INFINITY));
+    assert_eq!(modf(-0.0).1.to_bits(), (-0.0f64).to_bits());
+    assert!(modf(f64::NAN).0.is_nan() && modf(f64::NAN).1.is_nan());
+}
+
+#[test]
+fn test_next() {
+    let specials = [
+        0.0,
+        -0.0,
+        5e-324,
+        -5e-324,
+        f64::MIN_POSITIVE,
+        1.0,
+        f64::MAX,
+        f64::MIN,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ];
+    for x in float_bits_sample().chain(specials) {
+        let up = next_up(x);
+        let down = next_down(x);
+        assert_eq!(up.to_bits(), x.next_up().to_bits(), "x: {x:e}");
+        assert_eq!(down.to_bits(), x.next_down().to_bits(), "x: {x:e}");
+        assert_eq!(
+            nextafter(x, f64::INFINITY).to_bits(),
+            up.to_bits(),
+            "x: {x:e}"
+        );
+        assert_eq!(
+            nextafter(x, f64::NEG_INFINITY).to_bits(),
+            down.to_bits(),
+            "x: {x:e}"
+        );
+        if x.is_finite() {
+            assert_eq!(ulp_distance(x, up), 1, "x: {x:e}");
+            assert_eq!(ulp_distance(down, x), 1, "x: {x:e}");
+        }
+        if x.is_finite() && x.abs() != f64::MAX {
+            assert_eq!(ulp(x), next_up(x.abs()) - x.abs(), "x: {x:e}");
+        }
+    }
+    assert_eq!(nextafter(0.0, -0.0).to_bits(), (-0.0f64).to_bits());
+    assert_eq!(nextafter(-0.0, 0.0).to_bits(), 0.0f64.to_bits());
+    assert_eq!(nextafter(5e-324, 0.0).to_bits(), 0.0f64.to_bits());
+    assert_eq!(nextafter(-5e-324, 0.0).to_bits(), (-0.0f64).to_bits());
+    assert_eq!(nextafter(f64::MAX, f64::INFINITY), f64::INFINITY);
+    assert_eq!(nextafter(2.0, 2.0), 2.0);
+    assert!(nextafter(f64::NAN, 1.0).is_nan());
+    assert!(nextafter(1.0, f64::NAN).is_nan());
+    assert!(next_up(f64::NAN).is_nan());
+    assert!(next_down(f64::NAN).is_nan());
+
+    assert_eq!(ulp(-1.0), f64::EPSILON);
+    assert_eq!(ulp(f64::MIN_POSITIVE), 5e-324);
+    assert_eq!(ulp(f64::MAX), 2f64.powi(971));
+    assert_eq!(ulp(f64::NEG_INFINITY), f64::INFINITY);
+    assert!(ulp(f64::NAN).is_nan());
+
+    assert_eq!(ulp_distance(0.0, -0.0), 0);
+    assert_eq!(ulp_distance(-1.0, 1.0), 2 * 1f64.to_bits());
+    assert_eq!(
+        ulp_distance(f64::NEG_INFINITY, f64::INFINITY),
+        2 * f64::INFINITY.to_bits()
+    );
+    assert_eq!(ulp_distance(1.0, f64::NAN), u64::MAX);
+}
+
+#[test]
+fn test_fma() {
+    // xorshift, so the triples cover all exponents and signs
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        f64::from_bits(state)
+    };
+    for k in 0..200_000 {
+        let (x, y, z) = (next(), next(), next());
+        // Mostly put `z` within reach of `x * y` so the addition interacts
+        let z = if k % 4 == 0 || !(x * y).is_normal() || z == 0.0 {
+            z
+        } else {
+            let (m, _) = frexp(z);
+            scalbn(m, ilogb(x * y) + k % 120 - 60)
+        };
+        if !(x.is_finite() && y.is_finite() && z.is_finite()) {
+            continue;
+        }
+        assert_eq!(
+            fma(x, y, z).to_bits(),
+            x.mul_add(y, z).to_bits(),
+            "{x:e}, {y:e}, {z:e}"
+        );
+    }
+    for x in float_loop(-10.0, 10.0, 0.137) {
+        for y in float_loop(-10.0, 10.0, 0.173) {
+            for z in [0.0, -0.0, -(x * y), 1.0, -1e-30, 5e-324, 1e300] {
+                assert_eq!(
+                    fma(x, y, z).to_bits(),
+                    x.mul_add(y, z).to_bits(),
+                    "{x:e}, {y:e}, {z:e}"
+                );
+            }
+        }
+    }
+    for (x, y, z) in [
+        // Subnormal results and the boundary to the smallest normal
+        (f64::MIN_POSITIVE, 0.5, 5e-324),
+        (
+            f64::MIN_POSITIVE,
+            1.0 - f64::EPSILON,
+            f64::MIN_POSITIVE * f64::EPSILON / 2.0,
+        ),
+        (1e-200, 1e-120, -5e-324),
+        (3e-310, 0.5, -1e-310),
+        // Overflow and cancellation at the top of the range
+        (f64::MAX, 2.0, -f64::MAX),
+        (f64::MAX, 1.0 + f64::EPSILON, 0.0),
+        (1.0 + f64::EPSILON, 1.0 - f64::EPSILON, -1.0),
+        (-0.0, 1.0, 0.0),
+        (0.0, -1.0, -0.0),
+        (f64::INFINITY, 0.0, 1.0),
+        (1.0, 1.0, f64::NEG_INFINITY),
+        (f64::INFINITY, 1.0, f64::NEG_INFINITY),
+    ] {
+        let expected = x.mul_add(y, z);
+        if expected.is_nan() {
+            assert!(fma(x, y, z).is_nan(), "{x:e}, {y:e}, {z:e}");
+        } else {
+            assert_eq!(
+                fma(x, y, z).to_bits(),
+                expected.to_bits(),
+                "{x:e}, {y:e}, {z:e}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_rounding() {
+    let check = |x: f64| {
+        assert_eq!(floor(x).to_bits(), x.floor().to_bits(), "floor x: {x:e}");
+        assert_eq!(ceil(x).to_bits(), x.ceil().to_bits(), "ceil x: {x:e}");
+        assert_eq!(trunc(x).to_bits(), x.trunc().to_bits(), "trunc x: {x:e}");
+        assert_eq!(round(x).to_bits(), x.round().to_bits(), "round x: {x:e}");
+        let even = x.round_ties_even().to_bits();
+        assert_eq!(
+            round_ties_even(x).to_bits(),
+            even,
+            "round_ties_even x: {x:e}"
+        );
+        assert_eq!(rint(x).to_bits(), even, "rint x: {x:e}");
+        assert_eq!(nearbyint(x).to_bits(), even, "nearbyint x: {x:e}");
+    };
+    for x in float_loop(-10.0, 10.0, 0.125) {
+        check(x);
+        check(x + 0.0625);
+    }
+    for x in bit_walk(true) {
+        check(x);
+    }
+    for x in [
+        0.0,
+        -0.0,
+        0.5,
+        0.49999999999999994,
+        1.5,
+        2.5,
+        4503599627370495.5,
+        4503599627370496.0,
+        9007199254740991.0,
+        5e-324,
+        f64::MIN_POSITIVE,
+        f64::MAX,
+        f64::INFINITY,
+    ] {
+        check(x);
+        check(-x);
+    }
+    assert!(round(f64::NAN).is_nan());
+    assert!(rint(f64::NAN).is_nan());
+}
+
+#[test]
+fn test_sqrtf() {
+    for x in float_loop(0.0, 10.0, 0.01) {
+        let x = x as f32;
+        assert_eq!(sqrtf(x), x.sqrt());
+    }
+}
+
+#[test]
+fn test_sqrtf_bits() {
+    // Walk every f32 exponent, including subnormals
+    for bits in (1..f32::INFINITY.to_bits()).step_by(0x1f3b) {
+        let x = f32::from_bits(bits);
+        assert_eq!(sqrtf(x).to_bits(), x.sqrt().to_bits(), "x: {x:e}");
+    }
+    for x in [
+        1e38,
+        1e-38,
+        1e-45,
+        f32::MAX,
+        f32::MIN_POSITIVE,
+        0.0,
+        -0.0,
+        f32::INFINITY,
+    ] {
+        assert_eq!(sqrtf(x).to_bits(), x.sqrt().to_bits(), "x: {x:e}");
+    }
+    assert!(sqrtf(-1.0).is_nan());
+    assert!(sqrtf(-f32::MIN_POSITIVE).is_nan());
+    assert!(sqrtf(f32::NEG_INFINITY).is_nan());
+    assert!(sqrtf(f32::NAN).is_nan());
+}
+
+#[test]
+fn test_reduce() {
+    for x in float_loop(-8.0 * PI, 8.0 * PI, 0.01) {
+        for k in 0..=8 {
+            let period = PI / (1 << k) as f64;
+            let r = rem_pi_over_2k(x, k);
+            assert!(r.hi.abs() <= period / 2.0 + 1e-15, "x: {x}, k: {k}");
+            assert!(r.lo.abs() <= 1e-15 * r.hi.abs(), "x: {x}, k: {k}");
+            float_eq!(r.quadrant as f64 * period + r.hi, x, 1e-13);
+        }
+        let r = rem_2pi(x);
+        assert!(r.hi.abs() <= PI);
+        float_eq!(r.quadrant as f64 * 2.0 * PI + r.hi, x, 1e-13);
+        assert_eq!(rem_pi(x), rem_pi_over_2k(x, 0));
+        assert_eq!(rem_pio2(x), rem_pi_over_2k(x, 1));
+    }
+    // Large arguments only keep the quadrant modulo 8, which is enough to recover sin
+    for x in [1e22, -1e100, 1e300, f64::MAX] {
+        let r = rem_pio2(x);
+        let s = match r.quadrant & 3 {
+            0 => r.hi.sin(),
+            1 => r.hi.cos(),
+            2 => -r.hi.sin(),
+            _ => -r.hi.cos(),
+        };
+        float_eq!(s, sin(x), 1e-15);
+        let r = rem_2pi(x);
+        float_eq!(r.hi.sin(), sin(x), 1e-15);
+        let r = rem_pi_over_2k(x, 3);
+        let eighth = r.quadrant.rem_euclid(16) as f64 * PI / 8.0;
+        float_eq!((eighth + r.hi).sin(), sin(x), 1e-15);
+    }
+    // Quadrants wrap modulo 2^32 for large k; references from mpmath, round(x * 2^k / π)
+    for (x, quadrants) in [
+        (
+            1.0,
+            [
+                -1560706194,
+                1173554909,
+                -1947857479,
+                399252338,
+                798504677,
+                1597009354,
+                -1100948588,
+                2093070119,
+                -108827058,
+                -217654115,
+                -435308231,
+                -870616462,
+                -1741232924,
+                812501449,
+                1625002897,
+                -1044961502,
+                -2089923004,
+                115121288,
+                230242576,
+                460485153,
+            ],
+        ),
+        (
+            -2.5,
+            [
+                1754281836,
+                -786403624,
+                -1572807247,
+                1149352802,
+                -1996261692,
+                302443912,
+                604887823,
+                1209775646,
+                -1875416004,
+                544135289,
+                1088270577,
+                -2118426141,
+                58115013,
+                116230027,
+                232460053,
+                464920107,
+                929840214,
+                1859680428,
+                -575606441,
+                -1151212882,
+            ],
+        ),
+        (
+            1000.0,
+            [
+                -1633065246,
+                1028836803,
+                2057673607,
+                -179620082,
+                -359240164,
+                -718480329,
+                -1436960658,
+                1421045981,
+                -1452875334,
+                1389216628,
+                -1516534040,
+                1261899216,
+                -1771168865,
+                752629566,
+                1505259133,
+                -1284449031,
+                1726069235,
+                -842828827,
+                -1685657653,
+                923651989,
+            ],
+        ),
+    ] {
+        for (k, quadrant) in (33..=52).zip(quadrants) {
+            assert_eq!(rem_pi_over_2k(x, k).quadrant, quadrant, "x: {x}, k: {k}");
+        }
+    }
+    assert!(rem_pio2(f64::NAN).hi.is_nan());
+    assert!(rem_2pi(f64::INFINITY).hi.is_nan());
+}
+
+#[test]
+fn test_complex() {
+    let c = Complex::new;
+    let close = |w: Complex, re: f64, im: f64| {
+        let tol = 4e-16 * re.hypot(im);
+        assert!((w.re - re).abs() <= tol, "{w:?} != {re} + {im}i");
+        assert!((w.im - im).abs() <= tol, "{w:?} != {re} + {im}i");
+    };
+    let z = c(1.5, -2.25);
+    close(z.exp(), -2.815278859231167, -3.487082142415594);
+    close(z.sqrt(), 1.4498557612048848, -0.7759392555471053);
+    close(z.sin(), 4.784552064541835, -0.33184011851146644);
+    close(z.cos(), 0.3392957647149185, 4.6794168664494205);
+    close(z.tan(), 0.003205515147866466, -1.0222351594971195);
+    close(z.sinh(), -1.3375571890960114, -1.8303468697221983);
+    close(z.cosh(), -1.4777216701351554, -1.6567352726933955);
+    close(z.tanh(), 1.016334677559343, 0.09917250556037532);
+    close(z.pow(c(0.5, 1.25)), 4.102242571773995, 3.837710443899506);
+    // ln|z| near the unit circle, where ln(hypot) would cancel
+    float_eq!(c(0.6, 0.8).ln().re, 2.2204460492503132e-17_f64, 1e-32);
+    float_eq!(
+        c(1.0 + 2f64.powi(-30), 1e-5).ln().re,
+        9.813225740861655e-10_f64,
+        1e-25
+    );
+    // Scaled paths past exp overflow
+    close(
+        c(710.3, 1.0).cosh(),
+        8.146617414078546e307,
+        1.268760489049407e308,
+    );
+    close(
+        c(-710.3, 1.0).sinh(),
+        -8.146617414078546e307,
+        1.268760489049407e308,
+    );
+    assert_eq!(c(709.9, 1.5).exp().im, f64::INFINITY);
+    float_eq!(c(709.9, 1.5).exp().re, 1.4298832489523891e307_f64, 1e292);
+
+    for x in float_loop(-4.0, 4.0, 0.37) {
+        for y in float_loop(-4.0, 4.0, 0.41) {
+            let z = c(x, y);
+            let w = c(y, -x);
+            let scale = z.abs() * w.abs();
+            let p = z.mul(w);
+            float_eq!(p.re, x * y - y * -x, 1e-15 * scale);
+            float_eq!(p.im, x * -x + y * y, 1e-15 * scale);
+            let q = p.div(w);
+            float_eq!(q.re, x, 1e-15 * z.abs());
+            float_eq!(q.im, y, 1e-15 * z.abs());
+            float_eq!(z.arg(), y.atan2(x), 1e-15);
+            let e = z.exp();
+            float_eq!(e.re, x.exp() * y.cos(), 1e-14 * x.exp());
+            float_eq!(e.im, x.exp() * y.sin(), 1e-14 * x.exp());
+            let l = e.ln();
+            float_eq!(l.re, x, 1e-14);
+            let r = z.sqrt();
+            assert!(r.re >= 0.0);
+            let r2 = r.mul(r);
+            float_eq!(r2.re, x, 1e-15 * z.abs());
+            float_eq!(r2.im, y, 1e-15 * z.abs());
+            // sin² + cos² = 1
+            let (s, co) = (z.sin(), z.cos());
+            let one = s.mul(s).add(co.mul(co));
+            let big = s.norm_sqr() + co.norm_sqr();
+            float_eq!(one.re, 1.0_f64, 1e-15 * big);
+            float_eq!(one.im, 0.0_f64, 1e-15 * big);
+            let t = z.tan();
+            let ts = t.mul(co);
+            float_eq!(ts.re, s.re, 1e-14 * s.abs().max(1.0));
+            float_eq!(ts.im, s.im, 1e-14 * s.abs().max(1.0));
+            let ch = z.cosh();
+            assert_eq!(ch, c(-y, x).cos());
+            let th = z.tanh().mul(ch);
+            let sh = z.sinh();
+            float_eq!(th.re, sh.re, 1e-14 * sh.abs().max(1.0));
+            float_eq!(th.im, sh.im, 1e-14 * sh.abs().max(1.0));
+            assert_eq!(z.conj().exp(), z.exp().conj());
+            assert_eq!(-z, z.neg());
+            assert_eq!(z + w - w, z.add(w).sub(w));
+            assert_eq!(z * w / w, q);
+        }
+    }
+    for t in float_loop(-10.0, 10.0, 0.1) {
+        let u = Complex::cis(t);
+        assert_eq!((u.re, u.im), (cos(t), sin(t)));
+        float_eq!(u.abs(), 1.0_f64, 1e-15);
+    }
+    // Smith's algorithm does not overflow for large divisors
+    assert_eq!(c(1e300, 1e300).div(c(1e300, 1e300)), Complex::ONE);
+    let (big, small) = (2f64.powi(1020), 2f64.powi(-1021));
+    assert_eq!(c(1.0, 0.0).div(c(big, big)), c(small, -small));
+
+    // C99 Annex G special values
+    let (inf, nan) = (f64::INFINITY, f64::NAN);
+    let same = |w: Complex, re: f64, im: f64| {
+        let ok = |a: f64, b: f64| (a.is_nan() && b.is_nan()) || a.to_bits() == b.to_bits();
+        assert!(ok(w.re, re) && ok(w.im, im), "{w:?} != {re} + {im}i");
+    };
+    assert!(c(inf, nan).mul(c(2.0, 0.0)).is_infinite());
+    assert!(c(nan, inf).mul(c(0.0, 1.0)).is_infinite());
+    assert!(c(1e300, 1e300).mul(c(1e300, -1e300)).is_infinite());
+    assert!(c(1.0, 2.0).div(Complex::ZERO).is_infinite());
+    assert!(c(inf, nan).div(c(1.0, 1.0)).is_infinite());
+    same(c(1.0, 2.0).div(c(inf, nan)), 0.0, 0.0);
+    assert!(c(nan, nan).div(Complex::ZERO).is_nan());
+    assert!(c(nan, inf).is_infinite() && !c(nan, inf).is_nan());
+
+    same(c(-0.0, 0.0).ln(), -inf, PI);
+    same(c(0.0, -0.0).ln(), -inf, -0.0);
+    same(c(-inf, 1.0).ln(), inf, PI);
+    same(c(inf, inf).ln(), inf, PI / 4.0);
+    same(c(nan, -inf).ln(), inf, nan);
+    same(c(inf, nan).ln(), inf, nan);
+    same(c(1.0, nan).ln(), nan, nan);
+
+    same(c(-0.0, 0.0).sqrt(), 0.0, 0.0);
+    same(c(3.0, -0.0).sqrt(), 3f64.sqrt(), -0.0);
+    same(c(-4.0, -0.0).sqrt(), 0.0, -2.0);
+    same(c(nan, inf).sqrt(), inf, inf);
+    same(c(-inf, 1.0).sqrt(), 0.0, inf);
+    same(c(inf, -1.0).sqrt(), inf, -0.0);
+    same(c(-inf, nan).sqrt(), nan, inf);
+    same(c(inf, nan).sqrt(), inf, nan);
+    same(c(1.0, nan).sqrt(), nan, nan);
+    let tiny = c(0.0, 5e-324).sqrt();
+    float_eq!(tiny.re, 1.5717277847026288e-162_f64, 1e-177);
+    float_eq!(tiny.im, tiny.re, 1e-177);
+    close(
+        c(f64::MAX, f64::MAX).sqrt(),
+        1.4730945569055652e154,
+        6.1017574412827024e153,
+    );
+
+    same(c(0.0, -0.0).exp(), 1.0, -0.0);
+    same(c(-0.0, inf).exp(), nan, nan);
+    same(c(1.0, nan).exp(), nan, nan);
+    same(c(inf, 0.0).exp(), inf, 0.0);
+    same(c(-inf, 2.0).exp(), -0.0, 0.0);
+    same(c(-inf, inf).exp(), 0.0, 0.0);
+    same(c(inf, nan).exp(), inf, nan);
+    same(c(nan, -0.0).exp(), nan, -0.0);
+    same(c(nan, 1.0).exp(), nan, nan);
+
+    same(c(0.0, 0.0).cosh(), 1.0, 0.0);
+    assert!(c(0.0, inf).cosh().re.is_nan());
+    assert_eq!(c(0.0, inf).cosh().im, 0.0);
+    same(c(inf, 0.0).cosh(), inf, 0.0);
+    same(c(-inf, 0.0).cosh(), inf, -0.0);
+    same(c(inf, 1.0).cosh(), inf * 1f64.cos(), inf);
+    same(c(inf, inf).cosh(), inf, nan);
+    same(c(nan, 0.0).cosh(), nan, 0.0);
+    same(c(2.0, nan).cosh(), nan, nan);
+
+    same(c(-0.0, 0.0).sinh(), -0.0, 0.0);
+    same(c(0.0, inf).sinh(), 0.0, nan);
+    same(c(-inf, -0.0).sinh(), -inf, -0.0);
+    same(c(inf, 1.0).sinh(), inf, inf);
+    same(c(-inf, 4.0).sinh(), inf, -inf);
+    same(c(inf, nan).sinh(), inf, nan);
+    same(c(nan, -0.0).sinh(), nan, -0.0);
+    same(c(2.0, inf).sinh(), nan, nan);
+
+    same(c(-0.0, 0.0).tanh(), -0.0, 0.0);
+    same(c(inf, 1.0).tanh(), 1.0, 0.0);
+    same(c(-inf, -inf).tanh(), -1.0, -0.0);
+    same(c(inf, 2.0).tanh(), 1.0, -0.0);
+    same(c(0.0, inf).tanh(), 0.0, nan);
+    same(c(1.0, nan).tanh(), nan, nan);
+    same(c(nan, -0.0).tanh(), nan, -0.0);
+    same(c(-800.0, 1.0).tanh(), -1.0, 0.0);
+
+    // The circular functions follow from the hyperbolic ones
+    same(c(0.0, inf).sin(), 0.0, inf);
+    same(c(0.0, -inf).cos(), inf, 0.0);
+    same(c(0.0, inf).tan(), 0.0, 1.0);
+}
+
+#[test]
+fn test_twiddles() {
+    const TW: [(f64, f64); 1024] = twiddles::<1024>();
+    const INV: [(f64, f64); 1024] = inverse_twiddles::<1024>();
+    const HALF: [(f64, f64); 512] = half_twiddles::<512>();
+    const QUARTER: [(f64, f64); 256] = quarter_twiddles::<256>();
+    for (k, &(c, s)) in TW.iter().enumerate() {
+        let t = -2.0 * PI * k as f64 / 1024.0;
+        float_eq!(c, t.cos(), 1e-15);
+        float_eq!(s, t.sin(), 1e-15);
+        assert_eq!(INV[k], (c, 0.0 - s));
+        // Exact symmetries
+        assert_eq!(TW[(1024 - k) % 1024], INV[k]);
+        assert_eq!(TW[(k + 512) % 1024], (0.0 - c, 0.0 - s));
+        assert_eq!(TW[(k + 256) % 1024], (s, 0.0 - c));
+        assert_eq!(TW[(256 + 1024 - k) % 1024], (0.0 - s, 0.0 - c));
+        if k < 512 {
+            assert_eq!(HALF[k], TW[k]);
+        }
+        if k < 256 {
+            assert_eq!(QUARTER[k], TW[k]);
+        }
+    }
+    assert!(TW
+        .iter()
+        .all(|&(c, s)| c.to_bits() != (-0.0f64).to_bits() && s.to_bits() != (-0.0f64).to_bits()));
+    assert_eq!(HALF[..], twiddles::<1024>()[..512]);
+
+    // Sizes that are not powers of two
+    let tw = twiddles::<12>();
+    assert_eq!(tw[3], (0.0, -1.0));
+    assert_eq!(tw[2].0, 0.5);
+    assert_eq!(tw[4], (-0.5, tw[2].1));
+    for (k, &(c, s)) in twiddles::<1000>().iter().enumerate() {
+        let t = -2.0 * PI * k as f64 / 1000.0;
+        float_eq!(c, t.cos(), 1e-15);
+        float_eq!(s, t.sin(), 1e-15);
+    }
+    for (k, &(c, s)) in quarter_twiddles::<7>().iter().enumerate() {
+        let t = -PI * k as f64 / 14.0;
+        float_eq!(c, t.cos(), 1e-15);
+        float_eq!(s, t.sin(), 1e-15);
+    }
+    assert_eq!(twiddles::<1>(), [(1.0, 0.0)]);
+    assert_eq!(twiddles::<0>(), []);
+}
+
+#[test]
+fn test_window() {
+    fn cosine_sum_ref(a: &[f64], n: usize, m: usize) -> f64 {
+        let x = 2.0 * PI * n as f64 / m as f64;
+        a.iter()
+            .enumerate()
+            .map(|(k, &ak)| if k % 2 == 1 { -ak } else { ak } * (k as f64 * x).cos())
+            .sum()
+    }
+    fn check<const N: usize>(w: [f64; N], sym: Symmetry, f: impl Fn(usize, usize) -> f64) {
+        let m = if sym == Symmetry::Symmetric { N - 1 } else { N };
+        for (n, &v) in w.iter().enumerate() {
+            float_eq!(v, f(n, m), 1e-15);
+            // Exact symmetry about M/2
+            if n > 0 || sym == Symmetry::Symmetric {
+                assert_eq!(v, w[m - n], "n: {n}");
+            }
+        }
+    }
+    type Window = fn(Symmetry) -> [f64; 64];
+    let cases: [(&[f64], Window); 6] = [
+        (&[0.5, 0.5], hann),
+        (&[0.54, 0.46], hamming),
+        (&[0.42, 0.5, 0.08], blackman),
+        (&[0.35875, 0.48829, 0.14128, 0.01168], blackman_harris),
+        (&[0.3635819, 0.4891775, 0.1365995, 0.0106411], nuttall),
+        (
+            &[
+                0.21557895,
+                0.41663158,
+                0.277263158,
+                0.083578947,
+                0.006947368,
+            ],
+            flat_top,
+        ),
+    ];
+    for (a, window) in cases {
+        for sym in [Symmetry::Symmetric, Symmetry::Periodic] {
+            check(window(sym), sym, |n, m| cosine_sum_ref(a, n, m));
+        }
+    }
+    for sym in [Symmetry::Symmetric, Symmetry::Periodic] {
+        check(bartlett::<63>(sym), sym, |n, m| {
+            1.0 - (2.0 * n as f64 / m as f64 - 1.0).abs()
+        });
+        check(gaussian::<63>(7.5, sym), sym, |n, m| {
+            let d = (n as f64 - m as f64 / 2.0) / 7.5;
+            (-0.5 * d * d).exp()
+        });
+        check(tukey::<64>(0.3, sym), sym, |n, m| {
+            let x = n as f64 / m as f64;
+            let x = x.min(1.0 - x);
+            if x < 0.15 {
+                0.5 - 0.5 * (2.0 * PI * x / 0.3).cos()
+            } else {
+                1.0
+            }
+        });
+        check(kaiser::<64>(8.6, sym), sym, |n, m| {
+            let r = 2.0 * n as f64 / m as f64 - 1.0;
+            bessel_i0(8.6 * (1.0 - r * r).sqrt()) / bessel_i0(8.6)
+        });
+        assert_eq!(tukey::<16>(0.0, sym), [1.0; 16]);
+        assert_eq!(tukey::<16>(1.0, sym), hann::<16>(sym));
+        assert_eq!(kaiser::<16>(0.0, sym), [1.0; 16]);
+        assert_eq!(hann::<1>(sym), [1.0]);
+        assert_eq!(kaiser::<1>(5.0, sym), [1.0]);
+        assert_eq!(hann::<0>(sym), []);
+    }
+    // A periodic window is the start of the symmetric window one longer
+    assert_eq!(
+        hann::<64>(Symmetry::Periodic)[..],
+        hann::<65>(Symmetry::Symmetric)[..64]
+    );
+    assert_eq!(
+        kaiser::<64>(3.0, Symmetry::Periodic)[..],
+        kaiser::<65>(3.0, Symmetry::Symmetric)[..64]
+    );
+
+    let k = kaiser::<5>(14.0, Symmetry::Symmetric);
+    float_eq!(k[0], 7.726866835270368e-06_f64, 1e-15 * 7.7e-6);
+    float_eq!(k[1], 0.16493218754795202_f64, 1e-15 * 0.17);
+    // Large beta does not overflow; the error is that of rounding beta * sqrt(r)
+    let k = kaiser::<5>(800.0, Symmetry::Symmetric);
+    assert_eq!(k[0], 0.0);
+    float_eq!(k[1], 3.0458107472112754e-47_f64, 1e-13 * 3.05e-47);
+    assert_eq!(k[2], 1.0);
+}
+
+#[test]
+fn test_sinc() {
+    for (x, normalized, unnormalized) in [
+        (0.3, 0.8583936913341398_f64, 0.9850673555377986_f64),
+        (123.456, -0.002553733019723112, -0.006511934361819789),
+        (2f64.powi(-19), 0.9999999999940158, 0.9999999999993937),
+    ] {
+        float_eq!(sinc(x), normalized, 2.5e-16 * normalized.abs());
+        float_eq!(sinc(-x), normalized, 2.5e-16 * normalized.abs());
+        float_eq!(
+            sinc_unnormalized(x),
+            unnormalized,
+            2.5e-16 * unnormalized.abs()
+        );
+    }
+    float_eq!(sinc(1e10 + 0.5), 3.183098861678752e-11_f64, 1e-26);
+    for x in float_loop(-50.0, 50.0, 0.0137) {
+        let t = PI * x;
+        float_eq!(sinc(x), t.sin() / t, 1e-14);
+        float_eq!(sinc_unnormalized(x), x.sin() / x, 1e-15);
+        assert_eq!(sinc(x), sinc(-x));
+    }
+    for n in 1..=1000 {
+        assert_eq!(sinc(n as f64), 0.0);
+    }
+    // Continuous across the series cutoff
+    let cut = 2f64.powi(-20);
+    float_eq!(sinc(next_down(cut)), sinc(cut), 3e-16);
+    float_eq!(
+        sinc_unnormalized(next_down(cut)),
+        sinc_unnormalized(cut),
+        1e-16
+    );
+    assert_eq!(sinc(0.0), 1.0);
+    assert_eq!(sinc(-0.0), 1.0);
+    assert_eq!(sinc(5e-324), 1.0);
+    assert_eq!(sinc_unnormalized(0.0), 1.0);
+    assert_eq!(sinc(f64::INFINITY), 0.0);
+    assert_eq!(sinc(-1e300), 0.0);
+    assert_eq!(sinc_unnormalized(f64::NEG_INFINITY), 0.0);
+    assert!(sinc(f64::NAN).is_nan());
+    assert!(sinc_unnormalized(f64::NAN).is_nan());
+}
+
+/// Frequency response of `h` at `f` cycles per sample
+fn response(h: &[f64], f: f64) -> f64 {
+    let centre = (h.len() - 1) as f64 / 2.0;
+    h.iter()
+        .enumerate()
+        .map(|(n, &v)| v * (2.0 * PI * f * (n as f64 - centre)).cos())
+        .sum()
+}
+
+#[test]
+fn test_fir() {
+    // Direct windowed sinc
+    let w = hamming::<51>(Symmetry::Symmetric);
+    let raw: Vec<f64> = (0..51)
+        .map(|n| {
+            let d = n as f64 - 25.0;
+            let t = 2.0 * PI * 0.15 * d;
+            let ideal = if d == 0.0 { 0.3 } else { 0.3 * t.sin() / t };
+            ideal * w[n]
+        })
+        .collect();
+    let sum: f64 = raw.iter().sum();
+    let h = fir_lowpass::<51>(0.15, Window::Hamming);
+    for (a, b) in h.iter().zip(&raw) {
+        float_eq!(a, b / sum, 1e-15);
+    }
+
+    fn check<const TAPS: usize>(h: [f64; TAPS], pass: &[f64], stop: &[f64], tol: f64) {
+        for n in 0..TAPS {
+            assert_eq!(h[n], h[TAPS - 1 - n]);
+        }
+        for &f in pass {
+            float_eq!(response(&h, f), 1.0_f64, tol);
+        }
+        for &f in stop {
+            float_eq!(response(&h, f), 0.0_f64, tol);
+        }
+    }
+    for window in [
+        Window::Hamming,
+        Window::Blackman,
+        Window::BlackmanHarris,
+        Window::Nuttall,
+        Window::Kaiser(8.6),
+    ] {
+        check(
+            fir_lowpass::<101>(0.2, window),
+            &[0.0, 0.1],
+            &[0.3, 0.5],
+            3e-3,
+        );
+        check(
+            fir_lowpass::<100>(0.2, window),
+            &[0.0, 0.1],
+            &[0.3, 0.5],
+            3e-3,
+        );
+        check(
+            fir_highpass::<101>(0.2, window),
+            &[0.3, 0.5],
+            &[0.0, 0.1],
+            3e-3,
+        );
+        check(
+            fir_bandpass::<101>(0.1, 0.3, window),
+            &[0.2],
+            &[0.0, 0.02, 0.4, 0.5],
+            3e-3,
+        );
+        check(
+            fir_bandpass::<100>(0.1, 0.3, window),
+            &[0.2],
+            &[0.0, 0.02, 0.4],
+            3e-3,
+        );
+        check(
+            fir_bandstop::<101>(0.1, 0.3, window),
+            &[0.0, 0.02, 0.4, 0.5],
+            &[0.2],
+            3e-3,
+        );
+    }
+    // Exact normalization points
+    float_eq!(
+        response(&fir_lowpass::<33>(0.1, Window::Hann), 0.0),
+        1.0_f64,
+        1e-15
+    );
+    float_eq!(
+        response(&fir_highpass::<33>(0.1, Window::Hann), 0.5),
+        1.0_f64,
+        1e-15
+    );
+    float_eq!(
+        response(&fir_bandpass::<33>(0.1, 0.2, Window::Hann), 0.15),
+        1.0_f64,
+        1e-15
+    );
+    float_eq!(
+        response(&fir_bandstop::<33>(0.1, 0.2, Window::Hann), 0.0),
+        1.0_f64,
+        1e-15
+    );
+    assert_eq!(fir_lowpass::<1>(0.2, Window::Hann), [1.0]);
+}
+
+#[test]
+#[should_panic(expected = "odd number of taps")]
+fn test_fir_even_highpass() {
+    fir_highpass::<32>(0.2, Window::Hann);
+}
+
+#[test]
+#[should_panic(expected = "cutoff must be between 0 and 0.5")]
+fn test_fir_bad_cutoff() {
+    fir_lowpass::<31>(0.5, Window::Hann);
 }
 
 #[test]
@@ -109,3 +1471,74 @@ fn test_pow() {
         }
     }
 }
+
+#[test]
+fn test_expi() {
+    for x in float_loop(-3.0, 3.0, 0.0173) {
+        for n in -700..=700 {
+            let expected = x.powf(n as f64);
+            let got = expi(x, n);
+            if expected.is_finite() && expected != 0.0 {
+                let ulp = f64::from_bits(expected.abs().to_bits() + 1) - expected.abs();
+                assert!(
+                    (got - expected).abs() <= ulp,
+                    "expi({x}, {n}) = {got}, expected {expected}"
+                );
+            } else {
+                assert_eq!(got, expected, "expi({x}, {n})");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_powf() {
+    for x in float_loop(-10.0, 10.0, 1.0) {
+        for y in float_loop(-2.0, 3.0, 0.5) {
+            let (x, y) = (x as f32, y as f32);
+            if x != 0.0 {
+                float_eq!(powf(x, y), x.powf(y), 1e-6 * x.powf(y).abs().max(1.0));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_tgamma() {
+    // Γ(x + 1) = x Γ(x)
+    for x in float_loop(-5.0, 10.0, 0.01) {
+        let lhs = tgamma(x + 1.0);
+        let rhs = x * tgamma(x);
+        float_eq!(lhs, rhs, 1e-12 * lhs.abs().max(1.0));
+    }
+    float_eq!(tgamma(0.5), PI.sqrt(), 1e-15);
+    assert_eq!(tgamma(1.0), 1.0);
+    assert_eq!(tgamma(23.0), 1124000727777607680000.0);
+    // Integer arguments are exact when evaluated in const
+    const GAMMA_171: f64 = tgamma(171.0);
+    assert_eq!(GAMMA_171, 7.257415615307999e306);
+    assert_eq!(tgamma(172.0), f64::INFINITY);
+    assert_eq!(tgamma(0.0), f64::INFINITY);
+    assert_eq!(tgamma(-0.0), f64::NEG_INFINITY);
+    assert!(tgamma(-1.0).is_nan());
+    assert!(tgamma(f64::NEG_INFINITY).is_nan());
+}
+
+#[test]
+fn test_lgamma() {
+    for x in float_loop(-20.0, 20.0, 0.01) {
+        let g = tgamma(x);
+        if g.is_nan() {
+            // Negative integer
+            continue;
+        }
+        let (l, sign) = lgamma_r(x);
+        float_eq!(l, g.abs().ln(), 1e-12 * l.abs().max(1.0));
+        assert_eq!(lgamma(x), l);
+        assert_eq!(sign as f64, g.signum(), "x: {x}");
+    }
+    assert_eq!(lgamma(1.0), 0.0);
+    assert_eq!(lgamma(2.0), 0.0);
+    assert_eq!(lgamma(-2.0), f64::INFINITY);
+    float_eq!(lgamma(1000.0), 5905.220423209181_f64, 1e-9);
+}